@@ -0,0 +1,41 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use grid::{Grid, GridPos};
+
+fn neighbors_sweep(c: &mut Criterion) {
+    let grid = Grid::new(1000, vec![0u8; 1000 * 1000]);
+    let positions: Vec<GridPos> = (0..grid.size()).map(GridPos::new).collect();
+
+    let mut group = c.benchmark_group("neighbors sweep 1000x1000");
+    group.bench_function("collect get_neighbors", |b| {
+        b.iter(|| {
+            positions
+                .iter()
+                .map(|pos| {
+                    let neighbors: Vec<GridPos> =
+                        grid.get_neighbors(pos).into_iter().flatten().collect();
+                    neighbors.len()
+                })
+                .sum::<usize>()
+        })
+    });
+    group.bench_function("get_neighbors_flat", |b| {
+        b.iter(|| {
+            positions
+                .iter()
+                .map(|pos| grid.get_neighbors_flat(black_box(pos)).len())
+                .sum::<usize>()
+        })
+    });
+    group.bench_function("get_neighbors_compact", |b| {
+        b.iter(|| {
+            positions
+                .iter()
+                .map(|pos| grid.get_neighbors_compact(black_box(pos)).1)
+                .sum::<usize>()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, neighbors_sweep);
+criterion_main!(benches);
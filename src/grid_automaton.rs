@@ -0,0 +1,157 @@
+use super::grid_direction::Direction;
+use super::grid_grid::Grid;
+use super::grid_pos::GridPos;
+
+/// The 8 neighbors of a cell for the rules of `Grid::step`, in the order of `Direction::ALL`.
+/// Neighbors outside of the grid are `None`
+pub struct CellNeighborhood<'a, T> {
+    pos: GridPos,
+    values: [Option<&'a T>; 8],
+}
+
+impl<'a, T> CellNeighborhood<'a, T> {
+    fn of(grid: &'a Grid<T>, pos: GridPos) -> Self {
+        CellNeighborhood {
+            pos,
+            values: grid
+                .get_neighbors8(&pos)
+                .map(|neighbor| neighbor.and_then(|neighbor| grid.get(&neighbor))),
+        }
+    }
+
+    /// The position of the cell whose neighbors these are
+    pub fn pos(&self) -> GridPos {
+        self.pos
+    }
+
+    /// The neighbor in the direction, `None` outside of the grid
+    #[must_use]
+    pub fn get(&self, direction: Direction) -> Option<&'a T> {
+        self.values[direction as usize]
+    }
+
+    /// The neighbor in the direction, `border` outside of the grid
+    #[must_use]
+    pub fn get_or(&self, direction: Direction, border: &'a T) -> &'a T {
+        self.get(direction).unwrap_or(border)
+    }
+
+    /// All 8 neighbors, in the order of `Direction::ALL`
+    #[must_use]
+    pub fn values(&self) -> [Option<&'a T>; 8] {
+        self.values
+    }
+
+    /// The neighbors inside of the grid
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> + '_ {
+        self.values.iter().flatten().copied()
+    }
+
+    /// Counts the neighbors inside of the grid that match `pred`
+    pub fn count(&self, pred: impl Fn(&T) -> bool) -> usize {
+        self.iter().filter(|value| pred(value)).count()
+    }
+}
+
+impl<T> Grid<T> {
+    /// Computes the next generation of a cellular automaton: every cell of the new grid is
+    /// `rule(value, neighbors)` of the same cell in this grid
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let grid = Grid::new(3, vec![1, 2, 3, 4, 5, 6]);
+    /// let sums = grid.step(|value, neighbors| value + neighbors.iter().sum::<i32>());
+    /// assert_eq!(sums, Grid::new(3, vec![12, 21, 16, 12, 21, 16]));
+    ///
+    /// let above = grid.step(|_, neighbors| *neighbors.get_or(Direction::Up, &0));
+    /// assert_eq!(above, Grid::new(3, vec![0, 0, 0, 1, 2, 3]));
+    /// ```
+    pub fn step<U>(&self, rule: impl Fn(&T, &CellNeighborhood<T>) -> U) -> Grid<U> {
+        let mut next = Vec::with_capacity(self.size());
+        self.step_into(&rule, &mut next);
+        Grid::from_valid(self.width, next)
+    }
+
+    /// Replaces every cell with `rule(value, neighbors)`, where all rules see the values
+    /// from before the step
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let life = |&alive: &bool, neighbors: &CellNeighborhood<bool>| {
+    ///     matches!((alive, neighbors.count(|&n| n)), (true, 2) | (_, 3))
+    /// };
+    /// let blinker = Grid::new(3, vec![
+    ///     false, true, false,
+    ///     false, true, false,
+    ///     false, true, false]);
+    /// let mut grid = blinker.clone();
+    /// grid.step_in_place(life);
+    /// assert_eq!(grid, Grid::new(3, vec![
+    ///     false, false, false,
+    ///     true,  true,  true,
+    ///     false, false, false]));
+    /// grid.step_in_place(life);
+    /// assert_eq!(grid, blinker);
+    /// ```
+    pub fn step_in_place(&mut self, rule: impl Fn(&T, &CellNeighborhood<T>) -> T) {
+        let mut next = Vec::with_capacity(self.size());
+        self.step_into(&rule, &mut next);
+        self.data = next;
+    }
+
+    /// Steps with `rule` until a step changes nothing, at most `max_iters` times.
+    /// Returns the number of steps that changed the grid, or `None` when it was still changing
+    /// after `max_iters` steps
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let life = |&alive: &bool, neighbors: &CellNeighborhood<bool>| {
+    ///     matches!((alive, neighbors.count(|&n| n)), (true, 2) | (_, 3))
+    /// };
+    /// let mut blinker = Grid::new(3, vec![false, true, false, false, true, false, false, true, false]);
+    /// assert_eq!(blinker.step_until_stable(10, life), None);
+    ///
+    /// let mut block = Grid::new(2, vec![true; 4]);
+    /// assert_eq!(block.step_until_stable(10, life), Some(0));
+    ///
+    /// let mut lonely = Grid::new(2, vec![true, false, false, false]);
+    /// assert_eq!(lonely.step_until_stable(10, life), Some(1));
+    /// assert_eq!(lonely, Grid::new(2, vec![false; 4]));
+    /// ```
+    pub fn step_until_stable(
+        &mut self,
+        max_iters: usize,
+        rule: impl Fn(&T, &CellNeighborhood<T>) -> T,
+    ) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        let mut next = Vec::with_capacity(self.size());
+        for iteration in 0..=max_iters {
+            next.clear();
+            self.step_into(&rule, &mut next);
+            if next == self.data {
+                return Some(iteration);
+            }
+            if iteration == max_iters {
+                break;
+            }
+            std::mem::swap(&mut self.data, &mut next);
+        }
+        None
+    }
+
+    fn step_into<U>(&self, rule: &impl Fn(&T, &CellNeighborhood<T>) -> U, next: &mut Vec<U>) {
+        next.extend(
+            self.data.iter().enumerate().map(|(index, value)| {
+                rule(value, &CellNeighborhood::of(self, GridPos::new(index)))
+            }),
+        );
+    }
+}
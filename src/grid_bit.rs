@@ -0,0 +1,200 @@
+use std::fmt::Display;
+
+use super::grid_direction::Direction;
+use super::grid_grid::Grid;
+use super::grid_pos::GridPos;
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// A grid of booleans with one bit per cell, in the same row-major layout as `Grid`
+///
+/// # Example
+///
+/// ```
+/// # use grid::*;
+/// let mut visited = BitGrid::new_empty(100, 3);
+/// // cells 63 and 64 lie in different words
+/// for index in [0, 63, 64, 299] {
+///     visited.set(&GridPos::new(index), true);
+/// }
+/// assert!(visited.get(&GridPos::new(63)) && visited.get(&GridPos::new(64)));
+/// assert!(!visited.get(&GridPos::new(62)) && !visited.get(&GridPos::new(65)));
+/// assert!(visited.get(&visited.pos_at(2, 99).unwrap()));
+/// assert_eq!(visited.count_ones(), 4);
+///
+/// visited.set(&GridPos::new(64), false);
+/// visited.set(&GridPos::new(300), true); // outside, ignored
+/// assert!(!visited.get(&GridPos::new(300)));
+/// assert_eq!(visited.count_ones(), 3);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct BitGrid {
+    /// Cell `index` is bit `index % 64` of word `index / 64`, bits past the last cell are 0
+    words: Vec<u64>,
+    width: usize,
+    height: usize,
+}
+
+impl BitGrid {
+    /// Creates a grid with width and height and every cell `false`
+    ///
+    /// # Panics
+    ///
+    /// When `width * height` overflows `usize`
+    pub fn new_empty(width: usize, height: usize) -> Self {
+        let size = Grid::<bool>::cell_count(width, height);
+        BitGrid {
+            words: vec![0; size.div_ceil(WORD_BITS)],
+            width,
+            height: if width == 0 { 0 } else { height },
+        }
+    }
+
+    #[must_use]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    #[must_use]
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    #[must_use]
+    pub fn size(&self) -> usize {
+        self.width * self.height
+    }
+
+    /// Whether `pos` is a cell of this grid
+    #[must_use]
+    pub fn contains_pos(&self, pos: &GridPos) -> bool {
+        pos.pos < self.size()
+    }
+
+    /// Gets `GridPos` at the 0-indexed `row` and `col`, like `Grid::pos_at`
+    #[must_use]
+    pub fn pos_at(&self, row: usize, col: usize) -> Option<GridPos> {
+        (row < self.height && col < self.width).then(|| GridPos::new(row * self.width + col))
+    }
+
+    /// Gets the 0-indexed `(row, col)` of a `GridPos`, like `Grid::row_col`
+    #[must_use]
+    pub fn row_col(&self, pos: &GridPos) -> Option<(usize, usize)> {
+        self.contains_pos(pos)
+            .then(|| (pos.pos / self.width, pos.pos % self.width))
+    }
+
+    /// Gets the value of the cell (`false` outside the grid)
+    #[must_use]
+    pub fn get(&self, pos: &GridPos) -> bool {
+        self.contains_pos(pos) && self.words[pos.pos / WORD_BITS] & Self::mask(pos) != 0
+    }
+
+    /// Sets the value of the cell (nothing happens outside the grid)
+    pub fn set(&mut self, pos: &GridPos, value: bool) {
+        if !self.contains_pos(pos) {
+            return;
+        }
+        let word = &mut self.words[pos.pos / WORD_BITS];
+        if value {
+            *word |= Self::mask(pos);
+        } else {
+            *word &= !Self::mask(pos);
+        }
+    }
+
+    /// Gets the neighbors Up, Right, Down, Left (or None), like `Grid::get_neighbors`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let bits = BitGrid::new_empty(3, 2);
+    /// let grid = Grid::new(3, vec![false; 6]);
+    /// for index in 0..6 {
+    ///     let pos = GridPos::new(index);
+    ///     assert_eq!(bits.get_neighbors(&pos), grid.get_neighbors(&pos));
+    /// }
+    /// ```
+    pub fn get_neighbors(&self, pos: &GridPos) -> [Option<GridPos>; 4] {
+        Direction::ORTHOGONAL.map(|direction| {
+            let (row, col) = self.row_col(pos)?;
+            let (d_row, d_col) = direction.offset();
+            self.pos_at(
+                row.checked_add_signed(d_row)?,
+                col.checked_add_signed(d_col)?,
+            )
+        })
+    }
+
+    /// Number of `true` cells
+    #[must_use]
+    pub fn count_ones(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// The values in row-major order
+    pub fn iter(&self) -> impl Iterator<Item = bool> + '_ {
+        (0..self.size()).map(|index| self.get(&GridPos::new(index)))
+    }
+
+    fn mask(pos: &GridPos) -> u64 {
+        1 << (pos.pos % WORD_BITS)
+    }
+}
+
+impl From<&Grid<bool>> for BitGrid {
+    /// Packs the values into bits
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let values: Vec<bool> = (0..130).map(|index| index % 3 == 0).collect();
+    /// let grid = Grid::new(65, values);
+    /// let bits = BitGrid::from(&grid);
+    /// assert_eq!((bits.width(), bits.height()), (65, 2));
+    /// assert_eq!(bits.count_ones(), grid.count(&true));
+    /// assert!(bits.iter().eq(grid.iter().copied()));
+    /// assert_eq!(Grid::from(&bits), grid);
+    /// ```
+    fn from(grid: &Grid<bool>) -> Self {
+        let mut bits = BitGrid::new_empty(grid.width(), grid.height());
+        for (index, &value) in grid.iter().enumerate() {
+            bits.set(&GridPos::new(index), value);
+        }
+        bits
+    }
+}
+
+impl From<&BitGrid> for Grid<bool> {
+    fn from(bits: &BitGrid) -> Self {
+        Grid::from_valid(bits.width, bits.iter().collect())
+    }
+}
+
+impl Display for BitGrid {
+    /// Writes `#` for `true` and `.` for `false`, one line per row
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let mut bits = BitGrid::new_empty(3, 2);
+    /// bits.set(&GridPos::new(0), true);
+    /// bits.set(&GridPos::new(4), true);
+    /// assert_eq!(bits.to_string(), "#..\n.#.");
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (index, value) in self.iter().enumerate() {
+            if index > 0 && index % self.width == 0 {
+                f.write_str("\n")?;
+            }
+            f.write_str(if value { "#" } else { "." })?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,154 @@
+use super::grid_error::GridError;
+use super::grid_grid::Grid;
+
+/// Builds a grid row by row, checking the width of every row as it is pushed
+///
+/// # Example
+///
+/// ```
+/// # use grid::*;
+/// let mut builder = GridBuilder::new();
+/// for line in "123\n456\n".lines() {
+///     builder.push_row(line.chars())?;
+/// }
+/// let grid = builder.build()?;
+/// assert_eq!(grid.width(), 3);
+/// assert_eq!(grid.height(), 2);
+/// assert_eq!(grid.get(&grid.pos_at(1, 0).unwrap()), Some(&'4'));
+/// # Ok::<(), GridError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct GridBuilder<T> {
+    data: Vec<T>,
+    width: Option<usize>,
+    rows: usize,
+}
+
+impl<T> Default for GridBuilder<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> GridBuilder<T> {
+    /// Creates a builder, the first pushed row determines the width
+    #[must_use]
+    pub fn new() -> Self {
+        GridBuilder {
+            data: Vec::new(),
+            width: None,
+            rows: 0,
+        }
+    }
+
+    /// Creates a builder for rows of `width`, reserving space for `height_hint` rows
+    ///
+    /// # Panics
+    ///
+    /// When `width * height_hint` overflows `usize`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let mut builder = GridBuilder::with_capacity(2, 10);
+    /// assert_eq!(
+    ///     builder.push_row([1, 2, 3]).err(),
+    ///     Some(GridError::RaggedRows { expected: 2, found: 3, row: 0 }));
+    /// builder.push_row([1, 2])?.push_row([3, 4])?;
+    /// assert_eq!(builder.rows(), 2);
+    /// # Ok::<(), GridError>(())
+    /// ```
+    #[must_use]
+    pub fn with_capacity(width: usize, height_hint: usize) -> Self {
+        let capacity = Grid::<T>::cell_count(width, height_hint);
+        GridBuilder {
+            data: Vec::with_capacity(capacity),
+            width: Some(width),
+            rows: 0,
+        }
+    }
+
+    /// Appends a row.
+    /// A rejected row is not added, so the builder can still be used afterwards
+    ///
+    /// # Errors
+    ///
+    /// When the row does not have the width of the first row (or the width given to `with_capacity`).
+    /// The error names the 0-indexed row
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let mut builder = GridBuilder::new();
+    /// builder.push_row(vec![1, 2])?;
+    /// builder.push_row(vec![3, 4])?;
+    /// assert_eq!(
+    ///     builder.push_row(vec![5]).err(),
+    ///     Some(GridError::RaggedRows { expected: 2, found: 1, row: 2 }));
+    ///
+    /// let grid = builder.build()?;
+    /// assert_eq!(grid.height(), 2);
+    /// # Ok::<(), GridError>(())
+    /// ```
+    pub fn push_row(&mut self, row: impl IntoIterator<Item = T>) -> Result<&mut Self, GridError> {
+        match append_row(&mut self.data, self.width, row) {
+            Ok(found) => self.width = Some(found),
+            Err(found) => {
+                return Err(GridError::RaggedRows {
+                    expected: self.width.unwrap_or(found),
+                    found,
+                    row: self.rows,
+                });
+            }
+        }
+        self.rows += 1;
+        Ok(self)
+    }
+
+    /// Number of rows pushed so far
+    #[must_use]
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Finishes the grid
+    ///
+    /// # Errors
+    ///
+    /// When no row was pushed (`GridError::Empty`)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// assert_eq!(GridBuilder::<u8>::new().build().err(), Some(GridError::Empty));
+    /// assert_eq!(GridBuilder::<u8>::with_capacity(3, 3).build().err(), Some(GridError::Empty));
+    /// ```
+    pub fn build(self) -> Result<Grid<T>, GridError> {
+        match self.width {
+            Some(width) if self.rows > 0 => Ok(Grid::from_valid(width, self.data)),
+            _ => Err(GridError::Empty),
+        }
+    }
+}
+
+/// Appends `row` to `data`, if it has the `expected` length (any length, if `None`).
+/// Returns the length of the row, a rejected row is removed again
+pub(super) fn append_row<T>(
+    data: &mut Vec<T>,
+    expected: Option<usize>,
+    row: impl IntoIterator<Item = T>,
+) -> Result<usize, usize> {
+    let start = data.len();
+    data.extend(row);
+    let found = data.len() - start;
+    match expected {
+        Some(expected) if expected != found => {
+            data.truncate(start);
+            Err(found)
+        }
+        _ => Ok(found),
+    }
+}
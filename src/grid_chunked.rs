@@ -0,0 +1,154 @@
+use std::collections::HashMap;
+
+use super::grid_direction::Direction;
+use super::grid_grid::Grid;
+use super::grid_sparse::SparsePos;
+
+/// An unbounded grid stored as equally sized `Grid` chunks, which are generated on first access
+///
+/// Cells are addressed with global `SparsePos` coordinates; chunk `(0, 0)` holds the cells from `(0, 0)`
+/// to `(chunk_height - 1, chunk_width - 1)`, chunk `(-1, 0)` is the one above it
+///
+/// # Example
+///
+/// ```
+/// # use grid::*;
+/// let mut world = ChunkedGrid::new(16, 16, |row, col| row * 1000 + col);
+/// assert_eq!(world.get(&SparsePos::new(-1, 40)), &-960);
+/// world.put(&SparsePos::new(20, 3), 0);
+/// assert_eq!(world.get(&SparsePos::new(20, 3)), &0);
+///
+/// let mut chunks: Vec<_> = world.loaded_chunks().copied().collect();
+/// chunks.sort();
+/// assert_eq!(chunks, vec![(-1, 2), (1, 0)]);
+///
+/// world.unload((1, 0));
+/// assert_eq!(world.get_loaded(&SparsePos::new(20, 3)), None);
+/// assert_eq!(world.get(&SparsePos::new(20, 3)), &20003);
+/// ```
+pub struct ChunkedGrid<T, F> {
+    chunks: HashMap<(i64, i64), Grid<T>>,
+    chunk_width: usize,
+    chunk_height: usize,
+    generate: F,
+}
+
+impl<T, F> ChunkedGrid<T, F>
+where
+    F: FnMut(i64, i64) -> T,
+{
+    /// Creates a grid of `chunk_width` x `chunk_height` chunks, `generate(row, col)` creates the value of a cell
+    /// when its chunk is first accessed
+    ///
+    /// # Panics
+    ///
+    /// When a chunk dimension is 0
+    pub fn new(chunk_width: usize, chunk_height: usize, generate: F) -> Self {
+        assert!(
+            chunk_width > 0 && chunk_height > 0,
+            "Chunks of {chunk_width}x{chunk_height} cells are empty"
+        );
+        ChunkedGrid {
+            chunks: HashMap::new(),
+            chunk_width,
+            chunk_height,
+            generate,
+        }
+    }
+
+    #[must_use]
+    pub fn chunk_width(&self) -> usize {
+        self.chunk_width
+    }
+
+    #[must_use]
+    pub fn chunk_height(&self) -> usize {
+        self.chunk_height
+    }
+
+    /// Get a reference to the value at the position, generating its chunk if needed
+    pub fn get(&mut self, pos: &SparsePos) -> &T {
+        let (chunk, row, col) = self.locate(pos);
+        let grid = self.load(chunk);
+        let local = grid.pos_at(row, col).expect("in chunk bounds");
+        &grid.data[local.pos]
+    }
+
+    /// Gets a mutable reference to the value at the position, generating its chunk if needed
+    pub fn get_mut(&mut self, pos: &SparsePos) -> &mut T {
+        let (chunk, row, col) = self.locate(pos);
+        let grid = self.load(chunk);
+        let local = grid.pos_at(row, col).expect("in chunk bounds");
+        &mut grid.data[local.pos]
+    }
+
+    /// Places a new value at the position, generating its chunk if needed
+    pub fn put(&mut self, pos: &SparsePos, new_value: T) {
+        *self.get_mut(pos) = new_value;
+    }
+
+    /// Get a reference to the value at the position, if its chunk is loaded
+    #[must_use]
+    pub fn get_loaded(&self, pos: &SparsePos) -> Option<&T> {
+        let (chunk, row, col) = self.locate(pos);
+        let grid = self.chunks.get(&chunk)?;
+        grid.get(&grid.pos_at(row, col)?)
+    }
+
+    /// Gets the neighbors Up, Right, Down, Left, like `SparseGrid::get_neighbors`
+    pub fn get_neighbors(&self, pos: &SparsePos) -> [Option<SparsePos>; 4] {
+        Direction::ORTHOGONAL.map(|direction| pos.neighbor(direction))
+    }
+
+    /// Gets the values of the neighbors Up, Right, Down, Left, generating the chunks they lie in if needed
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let mut world = ChunkedGrid::new(4, 4, |row, col| (row, col));
+    /// // the corner of chunk (0, 0), with the neighbors in 3 other chunks
+    /// let values = world.neighbor_values(&SparsePos::new(0, 0));
+    /// assert_eq!(values, [Some(&(-1, 0)), Some(&(0, 1)), Some(&(1, 0)), Some(&(0, -1))]);
+    /// assert_eq!(world.loaded_chunks().count(), 3);
+    /// ```
+    pub fn neighbor_values(&mut self, pos: &SparsePos) -> [Option<&T>; 4] {
+        let neighbors = self.get_neighbors(pos);
+        for neighbor in neighbors.iter().flatten() {
+            let (chunk, _, _) = self.locate(neighbor);
+            self.load(chunk);
+        }
+        neighbors.map(|neighbor| neighbor.and_then(|neighbor| self.get_loaded(&neighbor)))
+    }
+
+    /// The coordinates of the generated chunks, in no particular order
+    pub fn loaded_chunks(&self) -> impl Iterator<Item = &(i64, i64)> {
+        self.chunks.keys()
+    }
+
+    /// Drops a chunk and returns it, it is generated again on the next access
+    pub fn unload(&mut self, chunk: (i64, i64)) -> Option<Grid<T>> {
+        self.chunks.remove(&chunk)
+    }
+
+    /// The chunk of a position and the `(row, col)` inside it
+    fn locate(&self, pos: &SparsePos) -> ((i64, i64), usize, usize) {
+        let (height, width) = (self.chunk_height as i64, self.chunk_width as i64);
+        (
+            (pos.row.div_euclid(height), pos.col.div_euclid(width)),
+            pos.row.rem_euclid(height) as usize,
+            pos.col.rem_euclid(width) as usize,
+        )
+    }
+
+    fn load(&mut self, chunk: (i64, i64)) -> &mut Grid<T> {
+        let (width, height) = (self.chunk_width, self.chunk_height);
+        let generate = &mut self.generate;
+        self.chunks.entry(chunk).or_insert_with(|| {
+            let (top, left) = (chunk.0 * height as i64, chunk.1 * width as i64);
+            Grid::from_fn(width, height, |row, col| {
+                generate(top + row as i64, left + col as i64)
+            })
+        })
+    }
+}
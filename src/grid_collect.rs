@@ -0,0 +1,56 @@
+use super::grid_builder::append_row;
+use super::grid_error::GridError;
+use super::grid_grid::Grid;
+
+/// Collects an iterator of row-major values into a grid
+pub trait IntoGrid<T>: Iterator<Item = T> + Sized {
+    /// Collects the values into a grid of the given width
+    ///
+    /// # Errors
+    ///
+    /// When the width is 0 (`GridError::ZeroWidth`, with the lower bound of the `size_hint` as `len`;
+    /// the iterator is not consumed), or the values do not fill complete rows (`GridError::IncompleteRow`)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let input = "#..#\n.##.\n";
+    /// let grid = input.chars().filter(|c| !c.is_whitespace()).collect_grid(4).unwrap();
+    /// assert_eq!(grid.height(), 2);
+    /// assert_eq!(grid.get(&grid.pos_at(1, 1).unwrap()), Some(&'#'));
+    ///
+    /// assert_eq!((0..7).collect_grid(3).err(), Some(GridError::IncompleteRow { width: 3, len: 7 }));
+    /// assert_eq!((0..6).collect_grid(0).err(), Some(GridError::ZeroWidth { len: 6 }));
+    /// // Returns right away, also for endless iterators
+    /// assert!(std::iter::repeat(1).collect_grid(0).is_err());
+    /// ```
+    fn collect_grid(self, width: usize) -> Result<Grid<T>, GridError>;
+}
+
+impl<T, I> IntoGrid<T> for I
+where
+    I: Iterator<Item = T>,
+{
+    fn collect_grid(mut self, width: usize) -> Result<Grid<T>, GridError> {
+        if width == 0 {
+            return Err(GridError::ZeroWidth {
+                len: self.size_hint().0,
+            });
+        }
+        let mut data = Vec::with_capacity(self.size_hint().0);
+        loop {
+            match append_row(&mut data, Some(width), self.by_ref().take(width)) {
+                Ok(_) => {}
+                Err(0) => break,
+                Err(found) => {
+                    return Err(GridError::IncompleteRow {
+                        width,
+                        len: data.len() + found,
+                    });
+                }
+            }
+        }
+        Ok(Grid::from_valid(width, data))
+    }
+}
@@ -0,0 +1,133 @@
+use super::grid_grid::Grid;
+use super::grid_pos::GridPos;
+
+/// A cell of a grid, as its 0-indexed row and column.
+/// Coordinates are ordered by row, then column (row-major)
+///
+/// # Example
+///
+/// ```
+/// # use grid::*;
+/// let coord = Coord::new(1, 2);
+/// assert_eq!(coord.offset(-1, 1), Some(Coord::new(0, 3)));
+/// assert_eq!(coord.offset(-2, 0), None);
+/// assert_eq!(Coord::from((1, 2)), coord);
+/// assert_eq!(<(usize, usize)>::from(coord), (1, 2));
+///
+/// let mut sorted = vec![Coord::new(1, 0), Coord::new(0, 5), Coord::new(0, 1)];
+/// sorted.sort();
+/// assert_eq!(sorted, [Coord::new(0, 1), Coord::new(0, 5), Coord::new(1, 0)]);
+/// ```
+#[must_use]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Coord {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl Coord {
+    pub fn new(row: usize, col: usize) -> Self {
+        Coord { row, col }
+    }
+
+    /// Gets the coordinate `d_row` rows and `d_col` columns away (or None, if a component would be negative or overflow)
+    #[must_use]
+    pub fn offset(&self, d_row: isize, d_col: isize) -> Option<Coord> {
+        Some(Coord {
+            row: self.row.checked_add_signed(d_row)?,
+            col: self.col.checked_add_signed(d_col)?,
+        })
+    }
+}
+
+impl From<(usize, usize)> for Coord {
+    fn from((row, col): (usize, usize)) -> Self {
+        Coord::new(row, col)
+    }
+}
+
+impl From<Coord> for (usize, usize) {
+    fn from(coord: Coord) -> Self {
+        (coord.row, coord.col)
+    }
+}
+
+impl<T> Grid<T> {
+    /// Gets the `Coord` of a `GridPos` (or None if it is outside the grid), like `row_col`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let grid = Grid::new(3, vec![1, 2, 3, 4, 5, 6]);
+    /// let pos = grid.pos_at(1, 2).unwrap();
+    /// assert_eq!(grid.to_coord(&pos), Some(Coord::new(1, 2)));
+    /// assert_eq!(grid.to_pos(&Coord::new(1, 2)), Some(pos));
+    /// assert_eq!(grid.to_pos(&Coord::new(0, 3)), None);
+    /// assert_eq!(grid.to_coord(&GridPos::new(6)), None);
+    /// ```
+    #[must_use]
+    pub fn to_coord(&self, pos: &GridPos) -> Option<Coord> {
+        self.row_col(pos).map(Coord::from)
+    }
+
+    /// Gets the `GridPos` of a `Coord` (or None if it is outside the grid), like `pos_at`
+    #[must_use]
+    pub fn to_pos(&self, coord: &Coord) -> Option<GridPos> {
+        self.pos_at(coord.row, coord.col)
+    }
+
+    /// Calls `get` with the `GridPos` of `coord`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let mut grid = Grid::new(3, vec![1, 2, 3, 4, 5, 6]);
+    /// assert_eq!(grid.get_coord(&Coord::new(1, 0)), Some(&4));
+    /// grid.put_coord(&Coord::new(1, 0), 9);
+    /// *grid.get_coord_mut(&Coord::new(0, 0)).unwrap() += 10;
+    /// assert_eq!(grid.to_rows(), vec![vec![11, 2, 3], vec![9, 5, 6]]);
+    /// assert_eq!(grid.get_coord(&Coord::new(0, 3)), None);
+    /// ```
+    #[must_use]
+    pub fn get_coord(&self, coord: &Coord) -> Option<&T> {
+        self.get(&self.to_pos(coord)?)
+    }
+
+    /// Calls `get_mut` with the `GridPos` of `coord`
+    #[must_use]
+    pub fn get_coord_mut(&mut self, coord: &Coord) -> Option<&mut T> {
+        let pos = self.to_pos(coord)?;
+        self.get_mut(&pos)
+    }
+
+    /// Calls `put` with the `GridPos` of `coord`
+    pub fn put_coord(&mut self, coord: &Coord, new_value: T) {
+        if let Some(pos) = self.to_pos(coord) {
+            self.put(&pos, new_value);
+        }
+    }
+
+    /// Calls `get_neighbors` with the `GridPos` of `coord`, with the neighbors as `Coord`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let grid = Grid::new(3, vec![1, 2, 3, 4, 5, 6]);
+    /// assert_eq!(
+    ///     grid.get_neighbors_coord(&Coord::new(0, 2)),
+    ///     [None, None, Some(Coord::new(1, 2)), Some(Coord::new(0, 1))]);
+    /// ```
+    #[must_use]
+    pub fn get_neighbors_coord(&self, coord: &Coord) -> [Option<Coord>; 4] {
+        match self.to_pos(coord) {
+            Some(pos) => self
+                .get_neighbors(&pos)
+                .map(|neighbor| neighbor.and_then(|neighbor| self.to_coord(&neighbor))),
+            None => [None; 4],
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use std::fmt::Display;
+
+/// A compass direction on the grid
+///
+/// `Direction::ALL` lists all 8 directions clockwise starting with `Up`
+///
+/// # Example
+///
+/// ```
+/// # use grid::Direction;
+/// assert_eq!(Direction::Up.opposite(), Direction::Down);
+/// assert_eq!(Direction::Up.turn_right(), Direction::Right);
+/// assert_eq!(Direction::UpLeft.turn_left(), Direction::DownLeft);
+/// assert_eq!(Direction::DownRight.offset(), (1, 1));
+/// assert_eq!(Direction::ALL.len(), 8);
+/// assert_eq!(Direction::Left.to_string(), "Left");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Direction {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction {
+    /// All 8 directions, clockwise starting with `Up`
+    pub const ALL: [Direction; 8] = [
+        Direction::Up,
+        Direction::UpRight,
+        Direction::Right,
+        Direction::DownRight,
+        Direction::Down,
+        Direction::DownLeft,
+        Direction::Left,
+        Direction::UpLeft,
+    ];
+
+    /// The 4 orthogonal directions, clockwise starting with `Up`
+    pub const ORTHOGONAL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// The 4 diagonal directions, clockwise starting with `UpRight`
+    pub const DIAGONAL: [Direction; 4] = [
+        Direction::UpRight,
+        Direction::DownRight,
+        Direction::DownLeft,
+        Direction::UpLeft,
+    ];
+
+    /// The `(row, col)` offset of a single step in this direction
+    #[must_use]
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Direction::Up => (-1, 0),
+            Direction::UpRight => (-1, 1),
+            Direction::Right => (0, 1),
+            Direction::DownRight => (1, 1),
+            Direction::Down => (1, 0),
+            Direction::DownLeft => (1, -1),
+            Direction::Left => (0, -1),
+            Direction::UpLeft => (-1, -1),
+        }
+    }
+
+    /// The direction pointing the other way
+    #[must_use]
+    pub fn opposite(self) -> Self {
+        self.rotate(4)
+    }
+
+    /// The direction 90 degrees counter-clockwise
+    #[must_use]
+    pub fn turn_left(self) -> Self {
+        self.rotate(6)
+    }
+
+    /// The direction 90 degrees clockwise
+    #[must_use]
+    pub fn turn_right(self) -> Self {
+        self.rotate(2)
+    }
+
+    /// Whether this is one of the 4 diagonal directions
+    #[must_use]
+    pub fn is_diagonal(self) -> bool {
+        let (d_row, d_col) = self.offset();
+        d_row != 0 && d_col != 0
+    }
+
+    /// Rotates clockwise by `steps` * 45 degrees
+    fn rotate(self, steps: usize) -> Self {
+        Direction::ALL[(self as usize + steps) % Direction::ALL.len()]
+    }
+}
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
@@ -0,0 +1,353 @@
+use std::fmt::{self, Display};
+use std::io;
+
+use super::grid_grid::Grid;
+use super::grid_pos::GridPos;
+
+/// Displays a grid with a custom rendering of every cell (see `Grid::display_with`)
+pub struct DisplayWith<'a, T, F> {
+    grid: &'a Grid<T>,
+    render: F,
+    cell_separator: &'a str,
+    row_separator: &'a str,
+}
+
+impl<'a, T, F, D> DisplayWith<'a, T, F>
+where
+    F: Fn(&T) -> D,
+    D: Display,
+{
+    /// Replaces the `", "` between the cells of a row and the `"\n"` between rows
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let grid = Grid::new(2, vec![1, 2, 3, 4]);
+    /// assert_eq!(grid.display_with(|value| value * 10).separators(" ", " | ").to_string(), "10 20 | 30 40");
+    /// ```
+    #[must_use]
+    pub fn separators(self, cell_separator: &'a str, row_separator: &'a str) -> Self {
+        DisplayWith {
+            cell_separator,
+            row_separator,
+            ..self
+        }
+    }
+}
+
+impl<T, F, D> Display for DisplayWith<'_, T, F>
+where
+    F: Fn(&T) -> D,
+    D: Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_rows(
+            f,
+            self.grid.width,
+            self.grid.data.iter().map(&self.render),
+            self.cell_separator,
+            self.row_separator,
+        )
+    }
+}
+
+impl<T> Grid<T> {
+    /// Displays the grid like `Display` does, but renders every cell through `f`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let maze = Grid::new(4, vec![
+    ///     true, true,  true,  true,
+    ///     true, false, false, true,
+    ///     true, true,  false, true]);
+    /// let rendered = maze.display_with(|&wall| if wall { '#' } else { '.' }).separators("", "\n");
+    /// assert_eq!(rendered.to_string(), "####\n#..#\n##.#");
+    ///
+    /// assert_eq!(maze.display_with(|&wall| u8::from(wall)).to_string(), "1, 1, 1, 1\n1, 0, 0, 1\n1, 1, 0, 1");
+    /// ```
+    pub fn display_with<F, D>(&self, f: F) -> DisplayWith<'_, T, F>
+    where
+        F: Fn(&T) -> D,
+        D: Display,
+    {
+        DisplayWith {
+            grid: self,
+            render: f,
+            cell_separator: ", ",
+            row_separator: "\n",
+        }
+    }
+
+    /// Same as `display_with(f).to_string()`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let grid = Grid::new(2, vec![Some(1), None, None, Some(4)]);
+    /// let text = grid.to_string_with(|cell| cell.map_or(String::from("-"), |value| value.to_string()));
+    /// assert_eq!(text, "1, -\n-, 4");
+    /// ```
+    #[must_use]
+    pub fn to_string_with<D: Display>(&self, f: impl Fn(&T) -> D) -> String {
+        self.display_with(f).to_string()
+    }
+}
+
+impl<T: Display> Grid<T> {
+    /// Writes the grid in the `Display` format to `w`, without building the whole string first.
+    /// Values are written one at a time, so wrap files into an `io::BufWriter`
+    ///
+    /// # Errors
+    ///
+    /// When writing to `w` fails
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let grid = Grid::new(3, vec![1, 2, 3, 4, 5, 6]);
+    /// let mut out = Vec::new();
+    /// grid.write_to(&mut out).unwrap();
+    /// assert_eq!(String::from_utf8(out).unwrap(), format!("{grid}"));
+    /// ```
+    pub fn write_to(&self, w: &mut impl io::Write) -> io::Result<()> {
+        write_rows_to(w, self.width, self.data.iter())
+    }
+}
+
+impl<T> Grid<T> {
+    /// Writes the grid like `display_with(f)` to `w`, without building the whole string first.
+    /// Values are written one at a time, so wrap files into an `io::BufWriter`
+    ///
+    /// # Errors
+    ///
+    /// When writing to `w` fails
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let grid = Grid::new(2, vec![true, false, false, true]);
+    /// let render = |&wall: &bool| if wall { '#' } else { '.' };
+    /// let mut out = Vec::new();
+    /// grid.write_to_with(&mut out, render).unwrap();
+    /// assert_eq!(String::from_utf8(out).unwrap(), grid.to_string_with(render));
+    /// ```
+    pub fn write_to_with<F, D>(&self, w: &mut impl io::Write, f: F) -> io::Result<()>
+    where
+        F: Fn(&T) -> D,
+        D: Display,
+    {
+        write_rows_to(w, self.width, self.data.iter().map(f))
+    }
+}
+
+/// `write_rows` with the `Display` separators into an `io::Write`
+fn write_rows_to(
+    w: &mut impl io::Write,
+    width: usize,
+    cells: impl Iterator<Item = impl Display>,
+) -> io::Result<()> {
+    let mut out = IoWriter {
+        inner: w,
+        error: None,
+    };
+    match (write_rows(&mut out, width, cells, ", ", "\n"), out.error) {
+        (Ok(()), _) => Ok(()),
+        (Err(_), Some(error)) => Err(error),
+        (Err(_), None) => Err(io::Error::other("formatter error")),
+    }
+}
+
+/// The 8 basic terminal colors
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Color {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+}
+
+impl Color {
+    /// Offset of the color in the ANSI codes `30..=37` (foreground) and `40..=47` (background)
+    fn ansi_offset(self) -> u8 {
+        self as u8
+    }
+}
+
+/// The glyph of a cell together with its colors for `Grid::render_ansi`.
+/// Displays as the glyph wrapped into the ANSI escape codes; without colors as only the glyph
+///
+/// # Example
+///
+/// ```
+/// # use grid::*;
+/// assert_eq!(CellStyle::new('#').to_string(), "#");
+/// assert_eq!(CellStyle::new('@').foreground(Color::Red).to_string(), "\x1b[31m@\x1b[0m");
+/// assert_eq!(
+///     CellStyle::new("ab").foreground(Color::White).background(Color::Blue).to_string(),
+///     "\x1b[37;44mab\x1b[0m");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub struct CellStyle {
+    pub glyph: String,
+    pub foreground: Option<Color>,
+    pub background: Option<Color>,
+}
+
+impl CellStyle {
+    /// Uncolored `glyph`
+    #[must_use]
+    pub fn new(glyph: impl Display) -> Self {
+        CellStyle {
+            glyph: glyph.to_string(),
+            foreground: None,
+            background: None,
+        }
+    }
+
+    /// Sets the color of the glyph
+    #[must_use]
+    pub fn foreground(self, color: Color) -> Self {
+        CellStyle {
+            foreground: Some(color),
+            ..self
+        }
+    }
+
+    /// Sets the color behind the glyph
+    #[must_use]
+    pub fn background(self, color: Color) -> Self {
+        CellStyle {
+            background: Some(color),
+            ..self
+        }
+    }
+}
+
+impl Display for CellStyle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.foreground, self.background) {
+            (None, None) => return f.write_str(&self.glyph),
+            (Some(fg), None) => write!(f, "\x1b[{}m", 30 + fg.ansi_offset())?,
+            (None, Some(bg)) => write!(f, "\x1b[{}m", 40 + bg.ansi_offset())?,
+            (Some(fg), Some(bg)) => write!(
+                f,
+                "\x1b[{};{}m",
+                30 + fg.ansi_offset(),
+                40 + bg.ansi_offset()
+            )?,
+        }
+        write!(f, "{}\x1b[0m", self.glyph)
+    }
+}
+
+impl<T> Grid<T> {
+    /// Renders the grid like `display_with`, with every cell styled by `style(pos, value)`.
+    /// Colored cells are reset right after their glyph, so no color runs past the end of a row
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let maze = Grid::new(2, vec!['.', '#', '.', '.']);
+    /// let path = [maze.pos_at(0, 0).unwrap(), maze.pos_at(1, 0).unwrap()];
+    /// let rendered = maze.render_ansi(|pos, &c| {
+    ///     let style = CellStyle::new(c);
+    ///     if path.contains(pos) { style.background(Color::Red) } else { style }
+    /// });
+    /// assert_eq!(rendered, "\x1b[41m.\x1b[0m, #\n\x1b[41m.\x1b[0m, .");
+    ///
+    /// let plain = maze.render_ansi(|_, &c| CellStyle::new(c));
+    /// assert_eq!(plain, maze.to_string_with(|&c| c));
+    /// ```
+    #[must_use]
+    pub fn render_ansi(&self, style: impl Fn(&GridPos, &T) -> CellStyle) -> String {
+        let cells = self
+            .data
+            .iter()
+            .enumerate()
+            .map(|(index, value)| style(&GridPos::new(index), value));
+        let mut rendered = String::new();
+        write_rows(&mut rendered, self.width, cells, ", ", "\n")
+            .expect("writing to a String does not fail");
+        rendered
+    }
+
+    /// Renders the grid like `Display`, with the cells at `positions` in red
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let grid = Grid::new(3, vec![1, 2, 3]);
+    /// let rendered = grid.highlight(&[grid.pos_at(0, 1).unwrap()]);
+    /// assert_eq!(rendered, "1, \x1b[31m2\x1b[0m, 3");
+    /// assert_eq!(grid.highlight(&[]), grid.to_string());
+    /// ```
+    #[must_use]
+    pub fn highlight(&self, positions: &[GridPos]) -> String
+    where
+        T: Display,
+    {
+        let mut highlighted = vec![false; self.size()];
+        for pos in positions {
+            if let Some(cell) = highlighted.get_mut(pos.pos) {
+                *cell = true;
+            }
+        }
+        self.render_ansi(|pos, value| {
+            let style = CellStyle::new(value);
+            if highlighted[pos.pos] {
+                style.foreground(Color::Red)
+            } else {
+                style
+            }
+        })
+    }
+}
+
+/// Passes formatted text on to an `io::Write`, keeping the io error that `fmt::Error` cannot carry
+struct IoWriter<'w, W> {
+    inner: &'w mut W,
+    error: Option<io::Error>,
+}
+
+impl<W: io::Write> fmt::Write for IoWriter<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.inner.write_all(s.as_bytes()).map_err(|error| {
+            self.error = Some(error);
+            fmt::Error
+        })
+    }
+}
+
+/// Writes `cells` in rows of `width`, with the separators only between cells and between rows
+pub(super) fn write_rows(
+    out: &mut impl fmt::Write,
+    width: usize,
+    cells: impl Iterator<Item = impl Display>,
+    cell_separator: &str,
+    row_separator: &str,
+) -> fmt::Result {
+    for (index, cell) in cells.enumerate() {
+        if index > 0 {
+            let separator = if index % width == 0 {
+                row_separator
+            } else {
+                cell_separator
+            };
+            out.write_str(separator)?;
+        }
+        write!(out, "{cell}")?;
+    }
+    Ok(())
+}
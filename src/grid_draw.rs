@@ -0,0 +1,148 @@
+use super::grid_grid::Grid;
+use super::grid_pos::GridPos;
+
+impl<T: Clone> Grid<T> {
+    /// Sets every cell of the Bresenham line from `a` to `b` (both included) to `value`.
+    /// Nothing is drawn when either position is outside the grid
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let mut grid = Grid::new(4, vec!['.'; 12]);
+    /// grid.draw_line(&grid.pos_at(0, 0).unwrap(), &grid.pos_at(2, 3).unwrap(), '#');
+    /// assert_eq!(grid.to_string_with(|&c| c).replace(", ", ""), "#...\n.##.\n...#");
+    /// ```
+    pub fn draw_line(&mut self, a: &GridPos, b: &GridPos, value: T) {
+        for pos in self.line(a, b).unwrap_or_default() {
+            self.data[pos.pos] = value.clone();
+        }
+    }
+
+    /// Sets the cells of the rectangle of `rows` x `cols` cells at `top_left` to `value`,
+    /// either all of them or only its outline. The rectangle is clipped to the grid
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let drawn = |grid: &Grid<char>| grid.to_string_with(|&c| c).replace(", ", "");
+    /// let mut grid = Grid::new(5, vec!['.'; 20]);
+    /// grid.draw_rect(&grid.pos_at(0, 1).unwrap(), 3, 4, '#', false);
+    /// assert_eq!(drawn(&grid), ".####\n.#..#\n.####\n.....");
+    ///
+    /// // Clipped at the bottom, so the bottom side is not drawn
+    /// let mut grid = Grid::new(5, vec!['.'; 20]);
+    /// grid.draw_rect(&grid.pos_at(2, 0).unwrap(), 5, 3, '#', false);
+    /// assert_eq!(drawn(&grid), ".....\n.....\n###..\n#.#..");
+    ///
+    /// grid.draw_rect(&grid.pos_at(0, 3).unwrap(), 2, 9, '+', true);
+    /// assert_eq!(drawn(&grid), "...++\n...++\n###..\n#.#..");
+    ///
+    /// // Huge sizes are clipped as well
+    /// let mut grid = Grid::new(3, vec!['.'; 9]);
+    /// grid.draw_rect(&grid.pos_at(1, 1).unwrap(), usize::MAX, 2, '#', false);
+    /// assert_eq!(drawn(&grid), "...\n.##\n.##");
+    /// let mut grid = Grid::new(3, vec!['.'; 9]);
+    /// grid.draw_rect(&grid.pos_at(1, 1).unwrap(), 2, usize::MAX, '#', false);
+    /// assert_eq!(drawn(&grid), "...\n.##\n.##");
+    /// ```
+    pub fn draw_rect(
+        &mut self,
+        top_left: &GridPos,
+        rows: usize,
+        cols: usize,
+        value: T,
+        filled: bool,
+    ) {
+        if filled {
+            return self.fill_region(top_left, rows, cols, value);
+        }
+        let Some((row, col)) = self.row_col(top_left) else {
+            return;
+        };
+        if rows == 0 || cols == 0 {
+            return;
+        }
+        let last_row = row.saturating_add(rows - 1);
+        let last_col = col.saturating_add(cols - 1);
+        // Clamped before casting, a side beyond the grid is not drawn
+        let span_end = last_col.min(self.width - 1) as isize;
+        for r in row..=last_row.min(self.height - 1) {
+            if r == row || r == last_row {
+                self.fill_span(r as isize, col as isize, span_end, &value);
+            } else {
+                self.fill_span(r as isize, col as isize, col as isize, &value);
+                if last_col < self.width {
+                    self.fill_span(r as isize, span_end, span_end, &value);
+                }
+            }
+        }
+    }
+
+    /// Sets the cells of the midpoint circle of `radius` around `center` to `value`,
+    /// either all of them or only its outline. The circle is clipped to the grid,
+    /// nothing is drawn when the center is outside the grid
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let drawn = |grid: &Grid<char>| grid.to_string_with(|&c| c).replace(", ", "");
+    /// let mut grid = Grid::new(5, vec!['.'; 25]);
+    /// grid.draw_circle(&grid.pos_at(2, 2).unwrap(), 2, '#', false);
+    /// assert_eq!(drawn(&grid), ".###.\n#...#\n#...#\n#...#\n.###.");
+    ///
+    /// let mut grid = Grid::new(5, vec!['.'; 25]);
+    /// grid.draw_circle(&grid.pos_at(2, 2).unwrap(), 2, '#', true);
+    /// assert_eq!(drawn(&grid), ".###.\n#####\n#####\n#####\n.###.");
+    ///
+    /// // Clipped by the top left corner
+    /// let mut grid = Grid::new(4, vec!['.'; 12]);
+    /// grid.draw_circle(&grid.pos_at(0, 0).unwrap(), 2, '#', false);
+    /// assert_eq!(drawn(&grid), "..#.\n..#.\n##..");
+    ///
+    /// let mut grid = Grid::new(3, vec!['.'; 9]);
+    /// grid.draw_circle(&grid.pos_at(1, 1).unwrap(), 0, '#', false);
+    /// assert_eq!(drawn(&grid), "...\n.#.\n...");
+    /// ```
+    pub fn draw_circle(&mut self, center: &GridPos, radius: usize, value: T, filled: bool) {
+        let Some((row, col)) = self.row_col(center) else {
+            return;
+        };
+        let (row, col) = (row as isize, col as isize);
+        let (mut x, mut y) = (radius as isize, 0);
+        let mut decision = 1 - x;
+        while x >= y {
+            // The 8 octants, as pairs mirrored across the center column
+            for (d_row, d_col) in [(y, x), (-y, x), (x, y), (-x, y)] {
+                if filled {
+                    self.fill_span(row + d_row, col - d_col, col + d_col, &value);
+                } else {
+                    self.fill_span(row + d_row, col - d_col, col - d_col, &value);
+                    self.fill_span(row + d_row, col + d_col, col + d_col, &value);
+                }
+            }
+            y += 1;
+            if decision < 0 {
+                decision += 2 * y + 1;
+            } else {
+                x -= 1;
+                decision += 2 * (y - x) + 1;
+            }
+        }
+    }
+
+    /// Sets the cells from `first_col` to `last_col` (both included) in `row` to `value`, clipped to the grid
+    fn fill_span(&mut self, row: isize, first_col: isize, last_col: isize, value: &T) {
+        if row < 0 || row as usize >= self.height || last_col < 0 || self.width == 0 {
+            return;
+        }
+        let first = first_col.max(0) as usize;
+        let last = (last_col as usize).min(self.width - 1);
+        if first <= last {
+            let start = row as usize * self.width;
+            self.data[start + first..=start + last].fill(value.clone());
+        }
+    }
+}
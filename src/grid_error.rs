@@ -0,0 +1,144 @@
+use std::fmt::Display;
+
+/// Errors of fallible grid operations
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum GridError {
+    /// There is no data to build a grid from
+    Empty,
+    /// A row does not have the width of the first row
+    RaggedRows {
+        expected: usize,
+        found: usize,
+        row: usize,
+    },
+    /// The data does not fill complete rows of the width
+    IncompleteRow { width: usize, len: usize },
+    /// The width is 0, but there is data
+    ZeroWidth { len: usize },
+    /// A row does not have the width of the grid
+    RowLength { expected: usize, found: usize },
+    /// A row index is past the last row
+    RowOutOfRange { row: usize, height: usize },
+    /// A column does not have the height of the grid
+    ColumnLength { expected: usize, found: usize },
+    /// A column index is past the last column
+    ColumnOutOfRange { col: usize, width: usize },
+    /// Two grids that need the same width have different widths
+    WidthMismatch { left: usize, right: usize },
+    /// Two grids that need the same height have different heights
+    HeightMismatch { left: usize, right: usize },
+    /// Two grids that need the same shape have different shapes, as `(width, height)`
+    ShapeMismatch {
+        left: (usize, usize),
+        right: (usize, usize),
+    },
+    /// The data does not fill complete layers of `width` x `height` cells
+    IncompleteLayer {
+        width: usize,
+        height: usize,
+        len: usize,
+    },
+    /// The number of cells of a `width` x `height` grid overflows `usize`
+    TooLarge { width: usize, height: usize },
+    /// The character at `row`, `col` is not an ASCII digit
+    NotADigit { row: usize, col: usize, found: char },
+}
+
+impl Display for GridError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GridError::Empty => write!(f, "No rows to build a grid from"),
+            GridError::RaggedRows {
+                expected,
+                found,
+                row,
+            } => write!(
+                f,
+                "Row {row} has length {found}, expected the width {expected} of the first row"
+            ),
+            GridError::IncompleteRow { width, len } => {
+                write!(f, "{len} values do not fill complete rows of width {width}")
+            }
+            GridError::ZeroWidth { len } => write!(f, "Width is 0, but there are {len} values"),
+            GridError::RowLength { expected, found } => {
+                write!(
+                    f,
+                    "Row has length {found}, expected the grid width {expected}"
+                )
+            }
+            GridError::RowOutOfRange { row, height } => {
+                write!(f, "Row {row} is out of range for a grid of height {height}")
+            }
+            GridError::ColumnLength { expected, found } => {
+                write!(
+                    f,
+                    "Column has length {found}, expected the grid height {expected}"
+                )
+            }
+            GridError::ColumnOutOfRange { col, width } => {
+                write!(
+                    f,
+                    "Column {col} is out of range for a grid of width {width}"
+                )
+            }
+            GridError::WidthMismatch { left, right } => {
+                write!(f, "Grid widths differ: {left} and {right}")
+            }
+            GridError::HeightMismatch { left, right } => {
+                write!(f, "Grid heights differ: {left} and {right}")
+            }
+            GridError::ShapeMismatch { left, right } => {
+                write!(
+                    f,
+                    "Grid shapes differ: {}x{} and {}x{}",
+                    left.0, left.1, right.0, right.1
+                )
+            }
+            GridError::NotADigit { row, col, found } => {
+                write!(
+                    f,
+                    "Expected a digit at row {row}, column {col}, found {found:?}"
+                )
+            }
+            GridError::IncompleteLayer { width, height, len } => {
+                write!(
+                    f,
+                    "{len} values do not fill complete layers of {width}x{height}"
+                )
+            }
+            GridError::TooLarge { width, height } => {
+                write!(f, "Grid of {width}x{height} cells overflows usize")
+            }
+        }
+    }
+}
+
+impl std::error::Error for GridError {}
+
+/// Errors of parsing a grid from text with a cell parser that fails with `E`
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ParseGridError<E> {
+    /// The lines do not form a grid
+    Grid(GridError),
+    /// The character at `row`, `col` could not be parsed
+    Cell { row: usize, col: usize, error: E },
+}
+
+impl<E> From<GridError> for ParseGridError<E> {
+    fn from(error: GridError) -> Self {
+        ParseGridError::Grid(error)
+    }
+}
+
+impl<E: Display> Display for ParseGridError<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseGridError::Grid(error) => error.fmt(f),
+            ParseGridError::Cell { row, col, error } => {
+                write!(f, "Invalid cell at row {row}, column {col}: {error}")
+            }
+        }
+    }
+}
+
+impl<E: std::fmt::Debug + Display> std::error::Error for ParseGridError<E> {}
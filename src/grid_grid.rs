@@ -1,32 +1,570 @@
 use std::fmt::Display;
 
+use super::grid_builder::GridBuilder;
+use super::grid_direction::Direction;
+use super::grid_display::write_rows;
+use super::grid_error::GridError;
 use super::grid_pos::GridPos;
 
+/// A rectangular grid of values, stored row by row
+///
+/// Grids are equal (and hash the same), when they have the same width and the same values:
+/// the same values in rows of a different width make a different grid
+///
+/// # Example
+///
+/// ```
+/// # use grid::*;
+/// use std::collections::HashSet;
+///
+/// let grid = Grid::new(3, vec![1, 2, 3, 4, 5, 6]);
+/// let mut next = grid.clone();
+/// assert_eq!(next, grid);
+/// next.put(&next.pos_at(0, 0).unwrap(), 9);
+/// assert_ne!(next, grid);
+/// assert_ne!(Grid::new(2, vec![1, 2, 3, 4, 5, 6]), grid);
+///
+/// let seen: HashSet<Grid<i32>> = [grid.clone(), next, grid.clone()].into_iter().collect();
+/// assert_eq!(seen.len(), 2);
+/// assert!(seen.contains(&Grid::new(3, vec![1, 2, 3, 4, 5, 6])));
+/// assert!(!seen.contains(&Grid::new(2, vec![1, 2, 3, 4, 5, 6])));
+///
+/// assert_eq!(format!("{grid:?}"), "Grid { width: 3, rows: [[1, 2, 3], [4, 5, 6]] }");
+/// let empty = Grid::<i32>::default();
+/// assert_eq!((empty.width(), empty.height()), (0, 0));
+/// ```
+///
+/// # Empty grids
+///
+/// A grid without values (width 0, or height 0) is a regular value:
+/// it has size and height 0, no position is inside it, and iterating or displaying it yields nothing
+///
+/// ```
+/// # use grid::*;
+/// let empty_grids: [Grid<u8>; 5] = [
+///     Grid::new(0, vec![]),
+///     grid![],
+///     Grid::new_empty(0, 5),
+///     Grid::new_empty(5, 0),
+///     Grid::filled(0, 5, 1),
+/// ];
+/// for grid in empty_grids {
+///     assert_eq!((grid.size(), grid.height()), (0, 0));
+///     assert_eq!(grid.pos_at(0, 0), None);
+///     let pos = GridPos::new(0);
+///     assert_eq!(grid.get(&pos), None);
+///     assert_eq!(grid.row_col(&pos), None);
+///     assert_eq!(grid.get_neighbors(&pos), [None; 4]);
+///     assert_eq!(grid.get_neighbors8(&pos), [None; 8]);
+///     assert_eq!(grid.get_at_offset(&pos, 1, 1), None);
+///     assert_eq!(grid.offset(&pos, 0, 0), None);
+///     assert_eq!(grid.iter().count(), 0);
+///     assert_eq!(grid.to_string(), "");
+///     assert_eq!(format!("{grid:#}"), "");
+///     assert_eq!(grid.transpose().size(), 0);
+///     assert_eq!(grid.rotate_cw().size(), 0);
+///     assert_eq!(grid.flip_horizontal().size(), 0);
+///     assert_eq!(grid.flood_region(&pos), vec![]);
+///     assert_eq!(grid.connected_components(|a, b| a == b).1, 0);
+///     assert_eq!(grid.positions_within(&pos, 2, Metric::Manhattan), vec![]);
+///     assert_eq!(grid.ring(&pos, 1), vec![]);
+///     assert_eq!(grid.to_rows(), Vec::<Vec<u8>>::new());
+///     assert_eq!(grid.transposed_view().iter().count(), 0);
+///     let mut grid = grid;
+///     grid.rotate_cw_in_place();
+///     grid.flip_horizontal_in_place();
+///     grid.flip_vertical_in_place();
+///     grid.roll_rows(1);
+///     grid.roll_cols(1);
+///     assert_eq!(grid.size(), 0);
+/// }
+/// ```
 #[must_use]
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct Grid<T> {
-    data: Vec<T>,
-    width: usize,
+    pub(super) data: Vec<T>,
+    pub(super) width: usize,
+    /// Number of rows, 0 for a width of 0. Always `width * height == data.len()`
+    pub(super) height: usize,
 }
 
 impl<T> Grid<T> {
+    /// Creates a grid with the given width from row-major data
+    ///
+    /// # Panics
+    ///
+    /// Panics, when the data does not fill complete rows (see `Grid::try_new`)
+    ///
+    /// ```should_panic
+    /// # use grid::Grid;
+    /// // The last row would only have a single cell
+    /// let grid = Grid::new(3, vec![1, 2, 3, 4]);
+    /// ```
     pub fn new(width: usize, data: Vec<T>) -> Self {
-        Grid { data, width }
+        Self::try_new(width, data).unwrap_or_else(|error| panic!("Grid malformed! {error}"))
+    }
+
+    /// Creates a grid with the given width from row-major data
+    ///
+    /// # Errors
+    ///
+    /// When the length of the data is not a multiple of the width,
+    /// or the width is 0 but there is data
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let grid = Grid::try_new(3, vec![1, 2, 3, 4, 5, 6]).unwrap();
+    /// assert_eq!(grid.height(), 2);
+    ///
+    /// assert_eq!(
+    ///     Grid::try_new(3, vec![1, 2, 3, 4]).err(),
+    ///     Some(GridError::IncompleteRow { width: 3, len: 4 }));
+    /// assert_eq!(Grid::try_new(0, vec![1]).err(), Some(GridError::ZeroWidth { len: 1 }));
+    /// assert_eq!(Grid::<i32>::try_new(0, vec![]).unwrap().size(), 0);
+    /// ```
+    pub fn try_new(width: usize, data: Vec<T>) -> Result<Self, GridError> {
+        Self::validate_shape(width, data.len())?;
+        Ok(Grid::from_valid(width, data))
+    }
+
+    /// Creates a grid from data that already passed `validate_shape`
+    pub(super) fn from_valid(width: usize, data: Vec<T>) -> Self {
+        debug_assert!(Self::validate_shape(width, data.len()).is_ok());
+        let height = Self::height_of(width, data.len());
+        Grid {
+            data,
+            width,
+            height,
+        }
+    }
+
+    /// Number of complete rows of `width` in `len` values (0 for a width of 0)
+    pub(super) fn height_of(width: usize, len: usize) -> usize {
+        len.checked_div(width).unwrap_or(0)
+    }
+
+    /// Recomputes `height` after the data or the width changed
+    pub(super) fn update_height(&mut self) {
+        self.height = Self::height_of(self.width, self.data.len());
+    }
+
+    /// Checks that `len` values fill complete rows of `width`
+    pub(super) fn validate_shape(width: usize, len: usize) -> Result<(), GridError> {
+        if width == 0 && len > 0 {
+            return Err(GridError::ZeroWidth { len });
+        }
+        if !len.is_multiple_of(width) {
+            return Err(GridError::IncompleteRow { width, len });
+        }
+        Ok(())
+    }
+
+    /// Creates a grid with the given width by cloning row-major data from a flat slice
+    ///
+    /// # Errors
+    ///
+    /// Same as `Grid::try_new`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let pixels = [0u8, 64, 128, 192, 255, 32];
+    /// let grid = Grid::from_slice(2, &pixels).unwrap();
+    /// assert_eq!(grid.height(), 3);
+    /// assert_eq!(grid.get(&grid.pos_at(2, 0).unwrap()), Some(&255));
+    ///
+    /// assert_eq!(
+    ///     Grid::from_slice(4, &pixels).err(),
+    ///     Some(GridError::IncompleteRow { width: 4, len: 6 }));
+    /// assert_eq!(Grid::from_slice(0, &pixels).err(), Some(GridError::ZeroWidth { len: 6 }));
+    /// ```
+    pub fn from_slice(width: usize, data: &[T]) -> Result<Self, GridError>
+    where
+        T: Clone,
+    {
+        Self::validate_shape(width, data.len())?;
+        Ok(Grid::from_valid(width, data.to_vec()))
+    }
+
+    /// Creates a grid with the given width from column-major data:
+    /// the first column from top to bottom, then the second column, and so on
+    ///
+    /// # Errors
+    ///
+    /// Same as `Grid::try_new`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// /*
+    ///     1,3,5,7,9,
+    ///     2,4,6,8,10,
+    ///  */
+    /// let grid = Grid::from_col_major(5, vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10]).unwrap();
+    /// assert_eq!(grid.width(), 5);
+    /// assert_eq!(grid.height(), 2);
+    /// assert_eq!(grid.iter().copied().collect::<Vec<_>>(), vec![1, 3, 5, 7, 9, 2, 4, 6, 8, 10]);
+    /// assert_eq!(grid.to_col_major(), vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+    ///
+    /// assert_eq!(
+    ///     Grid::from_col_major(3, vec![1, 2, 3, 4]).err(),
+    ///     Some(GridError::IncompleteRow { width: 3, len: 4 }));
+    /// ```
+    pub fn from_col_major(width: usize, data: Vec<T>) -> Result<Self, GridError> {
+        Self::validate_shape(width, data.len())?;
+        let height = data.len().checked_div(width).unwrap_or(0);
+        Ok(Grid::from_valid(width, data)
+            .reorder(width, |index| (index % width) * height + index / width))
+    }
+
+    /// Copies the values in column-major order:
+    /// the first column from top to bottom, then the second column, and so on
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// /*
+    ///     1,2,
+    ///     3,4,
+    ///     5,6,
+    ///     7,8,
+    ///     9,10,
+    ///  */
+    /// let grid = Grid::new(2, vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+    /// let columns = grid.to_col_major();
+    /// assert_eq!(columns, vec![1, 3, 5, 7, 9, 2, 4, 6, 8, 10]);
+    ///
+    /// let back = Grid::from_col_major(2, columns).unwrap();
+    /// assert_eq!(back.iter().collect::<Vec<_>>(), grid.iter().collect::<Vec<_>>());
+    /// ```
+    #[must_use]
+    pub fn to_col_major(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        let height = self.height();
+        (0..self.width)
+            .flat_map(|col| (0..height).map(move |row| self.data[row * self.width + col].clone()))
+            .collect()
+    }
+
+    /// Splits the grid into its rows, the inverse of `Grid::from(Vec<Vec<T>>)`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let rows = vec![vec![1, 2, 3], vec![4, 5, 6]];
+    /// let grid = Grid::from(rows.clone());
+    /// assert_eq!(grid.to_rows(), rows);
+    /// assert_eq!(grid.into_rows(), rows);
+    /// assert_eq!(Grid::<i32>::default().into_rows(), Vec::<Vec<i32>>::new());
+    /// ```
+    #[must_use]
+    pub fn into_rows(self) -> Vec<Vec<T>> {
+        let (width, height) = (self.width, self.height());
+        let mut values = self.data.into_iter();
+        (0..height)
+            .map(|_| values.by_ref().take(width).collect())
+            .collect()
+    }
+
+    /// Copies the rows of the grid, see `Grid::into_rows`
+    #[must_use]
+    pub fn to_rows(&self) -> Vec<Vec<T>>
+    where
+        T: Clone,
+    {
+        self.rows_slices().map(<[T]>::to_vec).collect()
+    }
+
+    /// Takes the grid apart into its width and row-major data, the inverse of `Grid::from_raw_parts`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let grid = Grid::new(3, vec![1, 2, 3, 4, 5, 6]);
+    /// let (width, data) = grid.clone().into_raw_parts();
+    /// assert_eq!((width, data.as_slice()), (3, [1, 2, 3, 4, 5, 6].as_slice()));
+    /// assert_eq!(Grid::from_raw_parts(width, data), Ok(grid));
+    /// assert_eq!(
+    ///     Grid::from_raw_parts(4, vec![1, 2, 3, 4, 5, 6]),
+    ///     Err(GridError::IncompleteRow { width: 4, len: 6 }));
+    /// ```
+    #[must_use]
+    pub fn into_raw_parts(self) -> (usize, Vec<T>) {
+        (self.width, self.data)
+    }
+
+    /// Creates a grid from its width and row-major data, the inverse of `Grid::into_raw_parts`
+    ///
+    /// # Errors
+    ///
+    /// Same as `Grid::try_new`
+    pub fn from_raw_parts(width: usize, data: Vec<T>) -> Result<Self, GridError> {
+        Self::try_new(width, data)
+    }
+
+    /// Creates a grid from the provided rows, the fallible version of `Grid::from`
+    ///
+    /// (A `TryFrom` impl is not possible next to the existing `From` impl)
+    ///
+    /// # Errors
+    ///
+    /// When there are no rows, or a row has a different width than the first row
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let grid = Grid::try_from_vec(vec![vec![1, 2], vec![3, 4]]).unwrap();
+    /// assert_eq!(grid.width(), 2);
+    ///
+    /// assert_eq!(Grid::<i32>::try_from_vec(vec![]).err(), Some(GridError::Empty));
+    /// assert_eq!(
+    ///     Grid::try_from_vec(vec![vec![1, 2], vec![3, 4], vec![5]]).err(),
+    ///     Some(GridError::RaggedRows { expected: 2, found: 1, row: 2 }));
+    /// ```
+    pub fn try_from_vec(data: Vec<Vec<T>>) -> Result<Self, GridError> {
+        let width = Self::validate_row_widths(data.iter().map(Vec::len))?;
+        let data = data.into_iter().flatten().collect();
+        Ok(Grid::from_valid(width, data))
+    }
+
+    /// Creates a grid from the provided rows, the fallible version of `Grid::from`
+    ///
+    /// # Errors
+    ///
+    /// When there are no rows, or a row has a different width than the first row
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let slices: &[&[i32]] = &[&[1, 2, 3], &[4, 5]];
+    /// assert_eq!(
+    ///     Grid::try_from_slices(slices).err(),
+    ///     Some(GridError::RaggedRows { expected: 3, found: 2, row: 1 }));
+    /// let slices: &[&[i32]] = &[];
+    /// assert_eq!(Grid::try_from_slices(slices).err(), Some(GridError::Empty));
+    /// ```
+    pub fn try_from_slices(data: &[&[T]]) -> Result<Self, GridError>
+    where
+        T: Clone,
+    {
+        let width = Self::validate_row_widths(data.iter().map(|slice| slice.len()))?;
+        let data = data.iter().flat_map(|slice| slice.to_vec()).collect();
+        Ok(Grid::from_valid(width, data))
+    }
+
+    /// Creates a grid from an iterator of rows, without collecting the rows first.
+    /// The first row determines the width
+    ///
+    /// # Errors
+    ///
+    /// When there are no rows (`GridError::Empty`), or a row has a different width than the first row
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let input = "123\n456";
+    /// let grid = Grid::from_rows(input.lines().map(|l| l.chars().map(|c| c.to_digit(10).unwrap()))).unwrap();
+    /// assert_eq!(grid.width(), 3);
+    /// assert_eq!(grid.get(&grid.pos_at(1, 2).unwrap()), Some(&6));
+    ///
+    /// assert_eq!(
+    ///     Grid::from_rows([vec![1, 2], vec![3, 4], vec![5, 6, 7]]).err(),
+    ///     Some(GridError::RaggedRows { expected: 2, found: 3, row: 2 }));
+    /// assert_eq!(Grid::<i32>::from_rows(Vec::<Vec<i32>>::new()).err(), Some(GridError::Empty));
+    /// ```
+    pub fn from_rows<I, R>(rows: I) -> Result<Self, GridError>
+    where
+        I: IntoIterator<Item = R>,
+        R: IntoIterator<Item = T>,
+    {
+        let mut builder = GridBuilder::new();
+        for row in rows {
+            builder.push_row(row)?;
+        }
+        builder.build()
+    }
+
+    /// Gets the common width of all rows
+    fn validate_row_widths(mut widths: impl Iterator<Item = usize>) -> Result<usize, GridError> {
+        let expected = widths.next().ok_or(GridError::Empty)?;
+        for (row, found) in widths.enumerate() {
+            if found != expected {
+                return Err(GridError::RaggedRows {
+                    expected,
+                    found,
+                    row: row + 1,
+                });
+            }
+        }
+        Ok(expected)
     }
 
     /// Creates a new grid with width and height
+    ///
+    /// # Panics
+    ///
+    /// When `width * height` overflows `usize`, see `try_new_empty`
     pub fn new_empty(width: usize, height: usize) -> Self
     where
         T: Default,
     {
-        let size = width * height;
+        Self::try_new_empty(width, height).unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Creates a new grid with width and height, with every cell set to the default value
+    ///
+    /// # Errors
+    ///
+    /// When `width * height` overflows `usize`, before anything is allocated
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let grid = Grid::<u8>::try_new_empty(3, 2).unwrap();
+    /// assert_eq!((grid.width(), grid.height()), (3, 2));
+    ///
+    /// let huge = usize::MAX / 2;
+    /// assert_eq!(
+    ///     Grid::<u8>::try_new_empty(huge, huge).err(),
+    ///     Some(GridError::TooLarge { width: huge, height: huge }));
+    /// ```
+    pub fn try_new_empty(width: usize, height: usize) -> Result<Self, GridError>
+    where
+        T: Default,
+    {
+        let size = Self::checked_cell_count(width, height)?;
         let mut data = Vec::with_capacity(size);
         data.resize_with(size, || Default::default());
 
-        Grid { data, width }
+        Ok(Grid::from_valid(width, data))
+    }
+
+    /// Creates a new grid with width and height, with every cell set to `value`
+    ///
+    /// # Panics
+    ///
+    /// When `width * height` overflows `usize`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let grid = Grid::filled(3, 2, '#');
+    /// assert_eq!(grid.width(), 3);
+    /// assert_eq!(grid.height(), 2);
+    /// assert!(grid.iter().all(|&c| c == '#'));
+    ///
+    /// let no_rows = Grid::filled(3, 0, '#');
+    /// assert_eq!((no_rows.size(), no_rows.height()), (0, 0));
+    /// let no_cols = Grid::filled(0, 3, '#');
+    /// assert_eq!((no_cols.size(), no_cols.height()), (0, 0));
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use grid::*;
+    /// let _ = Grid::filled(usize::MAX, 2, 0u8);
+    /// ```
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        let data = vec![value; Self::cell_count(width, height)];
+        Grid::from_valid(width, data)
+    }
+
+    /// Creates a new grid with width and height, calling `f` for every cell in row-major order.
+    /// Unlike [`Grid::filled`] this does not need `T: Clone`
+    ///
+    /// # Panics
+    ///
+    /// When `width * height` overflows `usize`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let grid = Grid::filled_with(2, 2, Vec::<u8>::new);
+    /// assert_eq!(grid.size(), 4);
+    /// assert!(grid.iter().all(Vec::is_empty));
+    ///
+    /// let mut next = 0;
+    /// let counted = Grid::filled_with(3, 1, || { next += 1; next });
+    /// assert_eq!(counted.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3]);
+    ///
+    /// assert_eq!(Grid::filled_with(0, 5, || 1).size(), 0);
+    /// assert_eq!(Grid::filled_with(5, 0, || 1).size(), 0);
+    /// ```
+    pub fn filled_with(width: usize, height: usize, f: impl FnMut() -> T) -> Self {
+        let size = Self::cell_count(width, height);
+        let mut data = Vec::with_capacity(size);
+        data.resize_with(size, f);
+        Grid::from_valid(width, data)
+    }
+
+    /// Number of cells of a `width` x `height` grid, panicking instead of wrapping around
+    pub(super) fn cell_count(width: usize, height: usize) -> usize {
+        Self::checked_cell_count(width, height).unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Number of cells of a `width` x `height` grid, `GridError::TooLarge` instead of wrapping around
+    pub(super) fn checked_cell_count(width: usize, height: usize) -> Result<usize, GridError> {
+        width
+            .checked_mul(height)
+            .ok_or(GridError::TooLarge { width, height })
+    }
+
+    /// Creates a new grid with width and height, calling `f(row, col)` for every cell.
+    /// `f` is called in row-major order: row by row, each row from left to right
+    ///
+    /// # Panics
+    ///
+    /// When `width * height` overflows `usize`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let grid = Grid::from_fn(4, 3, |row, col| row * 10 + col);
+    /// assert_eq!(grid.width(), 4);
+    /// assert_eq!(grid.height(), 3);
+    /// assert_eq!(grid.get(&grid.pos_at(0, 0).unwrap()), Some(&0));
+    /// assert_eq!(grid.get(&grid.pos_at(1, 3).unwrap()), Some(&13));
+    /// assert_eq!(grid.get(&grid.pos_at(2, 1).unwrap()), Some(&21));
+    ///
+    /// let mut calls = 0;
+    /// let order = Grid::from_fn(2, 2, |_, _| { calls += 1; calls });
+    /// assert_eq!(order.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use grid::*;
+    /// let _ = Grid::from_fn(usize::MAX / 2, 4, |_, _| 0u8);
+    /// ```
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(usize, usize) -> T) -> Self {
+        let mut data = Vec::with_capacity(Self::cell_count(width, height));
+        for row in 0..height {
+            for col in 0..width {
+                data.push(f(row, col));
+            }
+        }
+        Grid::from_valid(width, data)
     }
 
-    /// Gets Neighbors (or None) of the specified position.
-    /// First Neighbor is UP followed by the other 3 in a clockwise order
+    /// Gets Neighbors (or None) of the specified position, in the order of `Neighbors`.
+    /// See `neighbors_of` for named access
     /// # Example
     /// ```
     /// # use grid::{Grid, GridPos};
@@ -55,33 +593,12 @@ impl<T> Grid<T> {
     /// ```
     #[must_use]
     pub fn get_neighbors(&self, position: &GridPos) -> [Option<GridPos>; 4] {
-        let index = position.pos;
-
-        let pos_in_row = index % self.width;
-
-        let mut neighbors: [Option<GridPos>; 4] = Default::default();
-        // Up
-        if index >= self.width {
-            neighbors[0] = Some(GridPos::new(index - self.width));
-        }
-        // Right
-        if pos_in_row + 1 < self.width {
-            neighbors[1] = Some(GridPos::new(index + 1));
-        }
-        // Down
-        if index < self.size() - self.width {
-            neighbors[2] = Some(GridPos::new(index + self.width));
-        }
-        // Left
-        if pos_in_row > 0 {
-            neighbors[3] = Some(GridPos::new(index - 1));
-        }
-        neighbors
+        Direction::ORTHOGONAL.map(|direction| self.neighbor(position, direction))
     }
 
     /// Gets Neighbors (all Some(...)) of the specified position
     ///
-    /// Calls `get_neighbors(position).into_iter().flatten().collect::<Vec<GridPos>>();`
+    /// Same as `get_neighbors(position).into_iter().flatten().collect::<Vec<GridPos>>()`
     ///
     /// # Example
     /// ```
@@ -106,10 +623,34 @@ impl<T> Grid<T> {
     /// ```
     #[must_use]
     pub fn get_neighbors_flat(&self, position: &GridPos) -> Vec<GridPos> {
-        self.get_neighbors(position)
-            .into_iter()
-            .flatten()
-            .collect::<Vec<GridPos>>()
+        let (neighbors, len) = self.get_neighbors_compact(position);
+        neighbors[..len].to_vec()
+    }
+
+    /// Gets the Neighbors of the specified position without allocating:
+    /// the first `len` entries are the neighbors in the order of `get_neighbors_flat`,
+    /// the remaining entries are unspecified
+    ///
+    /// # Example
+    /// ```
+    /// # use grid::*;
+    /// let grid = Grid::new(3, (0..9).collect::<Vec<_>>());
+    /// let (neighbors, len) = grid.get_neighbors_compact(&GridPos::new(8));
+    /// assert_eq!(&neighbors[..len], &[GridPos::new(5), GridPos::new(7)]);
+    ///
+    /// let (neighbors, len) = grid.get_neighbors_compact(&GridPos::new(4));
+    /// assert_eq!(&neighbors[..len], grid.get_neighbors_flat(&GridPos::new(4)).as_slice());
+    /// assert_eq!(grid.get_neighbors_compact(&GridPos::new(9)).1, 0);
+    /// ```
+    #[must_use = "only the first `len` positions of the array are neighbors"]
+    pub fn get_neighbors_compact(&self, position: &GridPos) -> ([GridPos; 4], usize) {
+        let mut neighbors = [*position; 4];
+        let mut len = 0;
+        for neighbor in self.get_neighbors(position).into_iter().flatten() {
+            neighbors[len] = neighbor;
+            len += 1;
+        }
+        (neighbors, len)
     }
 
     /// Places a new value at the specified grid position
@@ -119,6 +660,54 @@ impl<T> Grid<T> {
         }
     }
 
+    /// Swaps the values at the two positions
+    ///
+    /// Returns whether they were swapped, i.e. both positions are inside the grid
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let mut grid = Grid::new(2, vec![1, 2, 3, 4]);
+    /// assert!(grid.swap(&GridPos::new(0), &GridPos::new(3)));
+    /// assert_eq!(grid, Grid::new(2, vec![4, 2, 3, 1]));
+    /// assert!(!grid.swap(&GridPos::new(0), &GridPos::new(4)));
+    /// ```
+    pub fn swap(&mut self, a: &GridPos, b: &GridPos) -> bool {
+        let inside = self.contains_pos(a) && self.contains_pos(b);
+        if inside {
+            self.data.swap(a.pos, b.pos);
+        }
+        inside
+    }
+
+    /// Applies `f` to the value at the specified position
+    ///
+    /// Returns whether `f` was called, i.e. the position is inside the grid
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let mut visits: Grid<u32> = Grid::new_empty(2, 2);
+    /// let pos = GridPos::new(3);
+    /// assert!(visits.modify(&pos, |v| *v += 1));
+    /// assert!(visits.modify(&pos, |v| *v += 1));
+    /// assert_eq!(visits.get(&pos), Some(&2));
+    ///
+    /// let mut called = false;
+    /// assert!(!visits.modify(&GridPos::new(4), |_| called = true));
+    /// assert!(!called);
+    /// ```
+    pub fn modify(&mut self, pos: &GridPos, f: impl FnOnce(&mut T)) -> bool {
+        if let Some(value) = self.get_mut(pos) {
+            f(value);
+            true
+        } else {
+            false
+        }
+    }
+
     /// Gets `GridPos` at 0-indexed grid
     ///
     /// # Example
@@ -141,24 +730,167 @@ impl<T> Grid<T> {
     /// assert_eq!(grid.pos_at(0, 2), Some(pos_3));
     /// assert_eq!(grid.pos_at(1, 2), Some(pos_6));
     /// ```
+    ///
+    /// Data that does not fill complete rows is rejected when the grid is built, so there is no
+    /// ragged last row: every position from `pos_at` holds a value, and `get_neighbors` agrees
+    ///
+    /// ```
+    /// # use grid::*;
+    /// assert_eq!(Grid::try_new(3, vec![0; 7]).err(), Some(GridError::IncompleteRow { width: 3, len: 7 }));
+    /// assert!(Grid::from_slice(3, &[0; 7]).is_err());
+    /// assert!(vec![0; 7].into_iter().collect_grid(3).is_err());
+    ///
+    /// let grid = Grid::new(3, (0..9).collect::<Vec<_>>());
+    /// for row in 0..=grid.height() {
+    ///     for col in 0..=grid.width() {
+    ///         let Some(pos) = grid.pos_at(row, col) else {
+    ///             assert!(row == grid.height() || col == grid.width());
+    ///             continue;
+    ///         };
+    ///         assert_eq!(grid.get(&pos), Some(&(row * 3 + col)));
+    ///         assert_eq!(grid.row_col(&pos), Some((row, col)));
+    ///         assert!(grid.get_neighbors(&pos).into_iter().flatten().all(|n| grid.get(&n).is_some()));
+    ///     }
+    /// }
+    /// let last_row = grid.get_neighbors(&grid.pos_at(2, 1).unwrap());
+    /// assert_eq!(last_row, [grid.pos_at(1, 1), grid.pos_at(2, 2), None, grid.pos_at(2, 0)]);
+    ///
+    /// let past_the_end = GridPos::new(9);
+    /// assert_eq!(grid.get(&past_the_end), None);
+    /// assert_eq!(grid.row_col(&past_the_end), None);
+    /// assert_eq!(grid.get_neighbors(&past_the_end), [None; 4]);
+    /// ```
     #[must_use]
     pub fn pos_at(&self, row: usize, col: usize) -> Option<GridPos> {
-        if col >= self.width {
-            return None;
-        }
-        let height = self.size() / self.width;
-        if row > height {
+        if col >= self.width || row >= self.height {
             return None;
         }
+        Some(GridPos::new(self.width * row + col))
+    }
+
+    /// Gets `GridPos` at the 0-indexed `(row, col)` tuple
+    ///
+    /// Same as `pos_at(row, col)`, the inverse of `row_col`
+    #[must_use]
+    pub fn pos_of(&self, (row, col): (usize, usize)) -> Option<GridPos> {
+        self.pos_at(row, col)
+    }
+
+    /// Every position of the rectangle with the opposite corners `a` and `b` (in any order), in row-major order.
+    /// Empty, if a corner is outside the grid
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let mut lights = Grid::new(5, vec![false; 20]);
+    /// let (a, b) = (lights.pos_at(3, 1).unwrap(), lights.pos_at(1, 2).unwrap());
+    /// let rect: Vec<_> = lights.positions_in_rect(&a, &b).collect();
+    /// assert_eq!(rect, [(1, 1), (1, 2), (2, 1), (2, 2), (3, 1), (3, 2)].map(|rc| lights.pos_of(rc).unwrap()));
+    /// assert!(lights.positions_in_rect(&b, &a).eq(rect.iter().copied()));
+    ///
+    /// for pos in rect {
+    ///     lights.put(&pos, true);
+    /// }
+    /// assert_eq!(lights.count(&true), 6);
+    ///
+    /// assert!(lights.positions_in_rect(&a, &a).eq([a]));
+    /// assert_eq!(lights.positions_in_rect(&a, &GridPos::new(20)).count(), 0);
+    /// ```
+    pub fn positions_in_rect(&self, a: &GridPos, b: &GridPos) -> impl Iterator<Item = GridPos> {
+        let width = self.width;
+        let corners = self.row_col(a).zip(self.row_col(b));
+        corners
+            .into_iter()
+            .flat_map(move |((row_a, col_a), (row_b, col_b))| {
+                let cols = col_a.min(col_b)..=col_a.max(col_b);
+                (row_a.min(row_b)..=row_a.max(row_b)).flat_map(move |row| {
+                    cols.clone().map(move |col| GridPos::new(row * width + col))
+                })
+            })
+    }
 
-        let pos = (self.width * row) + col;
-        if pos < self.size() {
-            Some(GridPos::new(pos))
+    /// Gets the 0-indexed `(row, col)` of a `GridPos` (or None if it is outside the grid)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::Grid;
+    /// /*
+    ///     1,2,3,
+    ///     4,5,6,
+    ///  */
+    /// let grid = Grid::new(3, vec![1,2,3,4,5,6]);
+    /// let pos_5 = grid.pos_at(1, 1).unwrap();
+    /// let pos_3 = grid.pos_at(0, 2).unwrap();
+    /// assert_eq!(grid.row_col(&pos_5), Some((1, 1)));
+    /// assert_eq!(grid.row_col(&pos_3), Some((0, 2)));
+    /// assert_eq!(grid.row_of(&pos_3), Some(0));
+    /// assert_eq!(grid.col_of(&pos_3), Some(2));
+    /// assert_eq!(grid.pos_of((1, 2)), grid.pos_at(1, 2));
+    /// assert_eq!(grid.pos_of(grid.row_col(&pos_5).unwrap()), Some(pos_5));
+    ///
+    /// let outside = grid::GridPos::new(6);
+    /// assert_eq!(grid.row_col(&outside), None);
+    /// assert_eq!(grid.row_of(&outside), None);
+    /// assert_eq!(grid.col_of(&outside), None);
+    /// ```
+    #[must_use]
+    pub fn row_col(&self, pos: &GridPos) -> Option<(usize, usize)> {
+        if self.contains_pos(pos) {
+            Some((pos.pos / self.width, pos.pos % self.width))
         } else {
             None
         }
     }
 
+    /// Calls `row_col(pos)` and returns the row
+    #[must_use]
+    pub fn row_of(&self, pos: &GridPos) -> Option<usize> {
+        self.row_col(pos).map(|(row, _)| row)
+    }
+
+    /// Calls `row_col(pos)` and returns the column
+    #[must_use]
+    pub fn col_of(&self, pos: &GridPos) -> Option<usize> {
+        self.row_col(pos).map(|(_, col)| col)
+    }
+
+    /// Gets the position `d_row` rows and `d_col` columns away from `position`
+    /// (or None, if that is outside the grid)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// /*
+    ///     1,2,3,
+    ///     4,5,6,
+    ///  */
+    /// let grid = Grid::new(3, vec![1,2,3,4,5,6]);
+    /// let pos_1 = grid.pos_at(0, 0).unwrap();
+    /// assert_eq!(grid.offset(&pos_1, 1, 2), grid.pos_at(1, 2));
+    /// assert_eq!(grid.offset(&pos_1, 0, 3), None);
+    /// assert_eq!(grid.offset(&pos_1, -1, 0), None);
+    /// assert_eq!(grid.offset(&grid.pos_at(1, 2).unwrap(), -1, -2), Some(pos_1));
+    ///
+    /// // Walking right until the edge
+    /// let mut pos = pos_1;
+    /// let mut walked = vec![*grid.get(&pos).unwrap()];
+    /// while let Some(next) = grid.offset(&pos, 0, 1) {
+    ///     walked.push(*grid.get(&next).unwrap());
+    ///     pos = next;
+    /// }
+    /// assert_eq!(walked, vec![1, 2, 3]);
+    /// ```
+    #[must_use]
+    pub fn offset(&self, position: &GridPos, d_row: isize, d_col: isize) -> Option<GridPos> {
+        let (row, col) = self.row_col(position)?;
+        let row = row.checked_add_signed(d_row)?;
+        let col = col.checked_add_signed(d_col)?;
+        self.pos_at(row, col)
+    }
+
     /// Get a reference offset by row, col
     ///
     /// # Example
@@ -184,19 +916,8 @@ impl<T> Grid<T> {
         row_offset: i8,
         col_offset: i8,
     ) -> Option<&T> {
-        let col = col_offset + (at_position.pos % self.width) as i8;
-        let row = row_offset + (at_position.pos / self.width) as i8;
-        if row < 0 || col < 0 {
-            return None;
-        }
-        let col = col as usize;
-        let row = row as usize;
-        let pos = self.pos_at(row, col);
-        if let Some(pos) = pos {
-            self.get(&pos)
-        } else {
-            None
-        }
+        self.offset(at_position, row_offset.into(), col_offset.into())
+            .and_then(|pos| self.get(&pos))
     }
 
     /// Calls `get_at_offset(position, 1, 0)`
@@ -216,10 +937,71 @@ impl<T> Grid<T> {
         self.get_at_offset(position, 0, 1)
     }
 
+    /// Whether `pos` is a cell of this grid.
+    /// All methods taking a `GridPos` check it this way
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let small = Grid::new(2, vec![1, 2, 3, 4]);
+    /// let large = Grid::new(3, vec![0; 9]);
+    /// let pos = large.pos_at(2, 2).unwrap();
+    /// assert!(large.contains_pos(&pos));
+    /// assert!(!small.contains_pos(&pos));
+    /// assert!(!Grid::<i32>::default().contains_pos(&GridPos::new(0)));
+    /// ```
+    #[must_use]
+    pub fn contains_pos(&self, pos: &GridPos) -> bool {
+        pos.pos < self.size()
+    }
+
+    /// Whether `pos` is a cell on the outer ring of the grid (false, if it is outside the grid)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let grid = Grid::new(3, vec![0; 9]);
+    /// assert!(grid.is_edge(&grid.pos_at(0, 1).unwrap()));
+    /// assert!(grid.is_edge(&grid.pos_at(1, 2).unwrap()));
+    /// assert!(!grid.is_edge(&grid.pos_at(1, 1).unwrap()));
+    /// assert!(!grid.is_edge(&GridPos::new(9)));
+    ///
+    /// let line = Grid::new(5, vec![0; 5]);
+    /// assert!((0..line.size()).map(GridPos::new).all(|pos| line.is_edge(&pos)));
+    /// ```
+    #[must_use]
+    pub fn is_edge(&self, pos: &GridPos) -> bool {
+        self.row_col(pos).is_some_and(|(row, col)| {
+            row == 0 || row + 1 == self.height() || col == 0 || col + 1 == self.width
+        })
+    }
+
+    /// Whether `pos` is one of the (up to 4) corner cells of the grid (false, if it is outside the grid)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let grid = Grid::new(3, vec![0; 6]);
+    /// let corners: Vec<_> = (0..grid.size()).map(GridPos::new).filter(|pos| grid.is_corner(pos)).collect();
+    /// assert_eq!(corners, [(0, 0), (0, 2), (1, 0), (1, 2)].map(|rc| grid.pos_of(rc).unwrap()));
+    ///
+    /// let line = Grid::new(5, vec![0; 5]);
+    /// assert_eq!((0..line.size()).map(GridPos::new).filter(|pos| line.is_corner(pos)).count(), 2);
+    /// ```
+    #[must_use]
+    pub fn is_corner(&self, pos: &GridPos) -> bool {
+        self.row_col(pos).is_some_and(|(row, col)| {
+            (row == 0 || row + 1 == self.height()) && (col == 0 || col + 1 == self.width)
+        })
+    }
+
     /// Get a reference to the value at the specified position
     #[must_use]
     pub fn get(&self, pos: &GridPos) -> Option<&T> {
-        if pos.pos < self.size() {
+        if self.contains_pos(pos) {
             Some(&self.data[pos.pos])
         } else {
             None
@@ -229,15 +1011,98 @@ impl<T> Grid<T> {
     /// Gets a mutable reference to the value at the specified position
     #[must_use]
     pub fn get_mut(&mut self, pos: &GridPos) -> Option<&mut T> {
-        if pos.pos < self.size() {
+        if self.contains_pos(pos) {
             Some(&mut self.data[pos.pos])
         } else {
             None
         }
     }
 
+    /// Get a reference to the value at the specified position, without bounds checking
+    ///
+    /// # Safety
+    ///
+    /// `pos` must be inside the grid (`pos.pos < self.size()`), e.g. because it was
+    /// obtained through `get_neighbors` or `pos_at` on this grid.
+    /// Debug builds assert this.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let grid = Grid::new(2, vec![1,2,3,4]);
+    /// let pos = grid.pos_at(1, 0).unwrap();
+    /// assert_eq!(unsafe { grid.get_unchecked(&pos) }, &3);
+    /// ```
+    #[must_use]
+    pub unsafe fn get_unchecked(&self, pos: &GridPos) -> &T {
+        debug_assert!(self.contains_pos(pos), "GridPos out of bounds");
+        // SAFETY: the caller guarantees `pos` is in bounds
+        unsafe { self.data.get_unchecked(pos.pos) }
+    }
+
+    /// Gets a mutable reference to the value at the specified position, without bounds checking
+    ///
+    /// # Safety
+    ///
+    /// `pos` must be inside the grid (`pos.pos < self.size()`).
+    /// Debug builds assert this.
+    #[must_use]
+    pub unsafe fn get_unchecked_mut(&mut self, pos: &GridPos) -> &mut T {
+        debug_assert!(self.contains_pos(pos), "GridPos out of bounds");
+        // SAFETY: the caller guarantees `pos` is in bounds
+        unsafe { self.data.get_unchecked_mut(pos.pos) }
+    }
+
+    /// Gets references to the values at the specified positions (or None, if a position is outside the grid)
+    ///
+    /// The order of the positions is preserved
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// /*
+    ///     1,2,3,
+    ///     4,5,6,
+    ///  */
+    /// let grid = Grid::new(3, vec![1,2,3,4,5,6]);
+    /// let positions = [GridPos::new(4), GridPos::new(9), GridPos::new(0)];
+    /// assert_eq!(grid.values_at(&positions), vec![Some(&5), None, Some(&1)]);
+    ///
+    /// let neighbors = grid.get_neighbors_flat(&GridPos::new(1));
+    /// assert_eq!(grid.values_at(&neighbors), vec![Some(&3), Some(&5), Some(&1)]);
+    /// ```
+    #[must_use]
+    pub fn values_at<'a>(&'a self, positions: &[GridPos]) -> Vec<Option<&'a T>> {
+        self.values_at_iter(positions).collect()
+    }
+
+    /// Lazy variant of `values_at`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let grid = Grid::new(2, vec![1,2,3,4]);
+    /// let positions = [GridPos::new(3), GridPos::new(4)];
+    /// let mut values = grid.values_at_iter(&positions);
+    /// assert_eq!(values.next(), Some(Some(&4)));
+    /// assert_eq!(values.next(), Some(None));
+    /// assert_eq!(values.next(), None);
+    /// ```
+    pub fn values_at_iter<'a, 'p>(
+        &'a self,
+        positions: &'p [GridPos],
+    ) -> impl Iterator<Item = Option<&'a T>> + 'p
+    where
+        'a: 'p,
+    {
+        positions.iter().map(|pos| self.get(pos))
+    }
+
     #[must_use]
-    pub fn iter(&self) -> GridIterator<T> {
+    pub fn iter(&self) -> GridIterator<'_, T> {
         GridIterator {
             grid: self,
             index: 0,
@@ -249,6 +1114,12 @@ impl<T> Grid<T> {
         self.width
     }
 
+    /// Number of (complete) rows
+    #[must_use]
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
     #[must_use]
     pub fn size(&self) -> usize {
         self.data.len()
@@ -281,6 +1152,74 @@ where
     }
 }
 
+impl<T> Grid<T>
+where
+    T: std::hash::Hash,
+{
+    /// A 64-bit hash of the width and the values, to cheaply recognize grid states seen before.
+    ///
+    /// The hash is not cryptographic: different grids can collide.
+    /// It is stable within one build, but may change between crate (or Rust) versions,
+    /// so do not persist it
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// use std::collections::HashMap;
+    ///
+    /// let mut grid = Grid::new(3, vec![0, 1, 2, 3, 4, 5]);
+    /// let start = grid.fingerprint();
+    /// assert_eq!(Grid::new(3, vec![0, 1, 2, 3, 4, 5]).fingerprint(), start);
+    /// assert_ne!(Grid::new(2, vec![0, 1, 2, 3, 4, 5]).fingerprint(), start);
+    ///
+    /// let mut changed = Grid::new(3, vec![0, 1, 2, 3, 4, 5]);
+    /// changed.put(&changed.pos_at(1, 2).unwrap(), 6);
+    /// assert_ne!(changed.fingerprint(), start);
+    ///
+    /// let mut seen = HashMap::new();
+    /// for step in 0..10 {
+    ///     if let Some(first) = seen.insert(grid.fingerprint(), step) {
+    ///         assert_eq!((first, step), (0, 6));
+    ///         break;
+    ///     }
+    ///     grid.apply(|value| *value = (*value + 1) % 6);
+    /// }
+    /// ```
+    #[must_use]
+    pub fn fingerprint(&self) -> u64 {
+        use std::hash::{DefaultHasher, Hasher};
+        let mut hasher = DefaultHasher::new();
+        std::hash::Hash::hash(self, &mut hasher);
+        hasher.finish()
+    }
+}
+
+impl<T> Grid<Option<T>> {
+    /// Gets a mutable reference to the value at the specified position,
+    /// placing `default_value` there first if the cell is empty
+    ///
+    /// Returns None (and drops `default_value`) if the position is outside the grid
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let mut grid: Grid<Option<u32>> = Grid::new_empty(2, 2);
+    /// let pos = GridPos::new(1);
+    /// *grid.get_or_put(&pos, 10).unwrap() += 1;
+    /// *grid.get_or_put(&pos, 10).unwrap() += 1;
+    /// assert_eq!(grid.get(&pos), Some(&Some(12)));
+    /// assert_eq!(grid.get(&GridPos::new(0)), Some(&None));
+    /// assert_eq!(grid.get_or_put(&GridPos::new(4), 10), None);
+    /// ```
+    #[must_use]
+    pub fn get_or_put(&mut self, pos: &GridPos, default_value: T) -> Option<&mut T> {
+        self.get_mut(pos)
+            .map(|value| value.get_or_insert(default_value))
+    }
+}
+
 pub struct GridIterator<'a, T> {
     grid: &'a Grid<T>,
     index: usize,
@@ -300,46 +1239,85 @@ impl<T> Display for Grid<T>
 where
     T: Display,
 {
+    /// Writes the rows on separate lines, with `", "` between the values of a row.
+    /// The alternate flag (`{:#}`) right-aligns the values of every column
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// assert_eq!(Grid::new(1, vec![7]).to_string(), "7");
+    /// assert_eq!(Grid::new(4, vec![1, 2, 3, 4]).to_string(), "1, 2, 3, 4");
+    /// assert_eq!(
+    ///     Grid::new(3, vec![1, 2, 3, 4, 5, 6, 7, 8, 9]).to_string(),
+    ///     "1, 2, 3\n4, 5, 6\n7, 8, 9");
+    /// assert_eq!(Grid::<i32>::new(0, vec![]).to_string(), "");
+    ///
+    /// let grid = Grid::new(3, vec![7, 42, 1000, 1000, 7, 42]);
+    /// assert_eq!(format!("{grid:#}"), "   7, 42, 1000\n1000,  7,   42");
+    /// ```
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "{}",
-            self.data
-                .iter()
-                .enumerate()
-                .map(|(index, item)| {
-                    let seperator = if index % self.width == self.width - 1 {
-                        ",\n"
-                    } else {
-                        ", "
-                    };
-                    format!("{item}{seperator}")
-                })
-                .fold(String::from(""), |acc, next| format!("{acc}{next}"))
-        )
+        if f.alternate() {
+            return self.fmt_aligned(f);
+        }
+        write_rows(f, self.width, self.data.iter(), ", ", "\n")
+    }
+}
+
+impl<T> Grid<T>
+where
+    T: Display,
+{
+    /// `Display` with every column padded to its widest value
+    fn fmt_aligned(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let cells: Vec<String> = self.data.iter().map(ToString::to_string).collect();
+        let mut widths = vec![0; self.width];
+        for (index, cell) in cells.iter().enumerate() {
+            let col = index % self.width;
+            widths[col] = widths[col].max(cell.chars().count());
+        }
+        for (index, cell) in cells.iter().enumerate() {
+            let col = index % self.width;
+            if index > 0 {
+                f.write_str(if col == 0 { "\n" } else { ", " })?;
+            }
+            write!(f, "{cell:>width$}", width = widths[col])?;
+        }
+        Ok(())
+    }
+}
+
+impl<T> Default for Grid<T> {
+    /// Creates an empty grid of width and height 0
+    fn default() -> Self {
+        Grid {
+            data: Vec::new(),
+            width: 0,
+            height: 0,
+        }
+    }
+}
+
+impl<T> std::fmt::Debug for Grid<T>
+where
+    T: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let rows: Vec<&[T]> = self.rows_slices().collect();
+        f.debug_struct("Grid")
+            .field("width", &self.width)
+            .field("rows", &rows)
+            .finish()
     }
 }
 
 impl<T> From<Vec<Vec<T>>> for Grid<T> {
     /// Creates a grid from the provided data.
     /// # Panics
-    /// Panics, when not all data-rows have to same width
+    /// Panics, when there are no rows or not all data-rows have to same width
+    /// (see `Grid::try_from_vec`)
     fn from(data: Vec<Vec<T>>) -> Self {
-        let all_widths: Vec<usize> = data.iter().map(std::vec::Vec::len).collect();
-        let first_width = &all_widths[0];
-        let all_widths_same = all_widths.iter().all(|width| *width == *first_width);
-
-        assert!(
-            all_widths_same,
-            "Grid malformed! Not all rows have the same width"
-        );
-
-        let grid = data.into_iter().flatten().collect();
-
-        Grid {
-            data: grid,
-            width: *first_width,
-        }
+        Self::try_from_vec(data).unwrap_or_else(|error| panic!("Grid malformed! {error}"))
     }
 }
 
@@ -349,21 +1327,64 @@ where
 {
     /// Creates a grid from the provided data.
     /// # Panics
-    /// Panics, when not all data-rows have to same width
+    /// Panics, when there are no rows or not all data-rows have to same width
+    /// (see `Grid::try_from_slices`)
     fn from(data: &[&[T]]) -> Self {
-        let all_widths: Vec<usize> = data.iter().map(|slice| slice.len()).collect();
-        let first_width = &all_widths[0];
-        let all_widths_same = all_widths.iter().all(|width| *width == *first_width);
+        Self::try_from_slices(data).unwrap_or_else(|error| panic!("Grid malformed! {error}"))
+    }
+}
+
+impl<T, const W: usize, const H: usize> From<[[T; W]; H]> for Grid<T> {
+    /// Creates a grid from `H` rows of width `W`, moving the values
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let grid = Grid::from([[1, 2, 3], [4, 5, 6]]);
+    /// assert_eq!(grid.width(), 3);
+    /// assert_eq!(grid.height(), 2);
+    /// assert_eq!(grid.get(&grid.pos_at(1, 0).unwrap()), Some(&4));
+    ///
+    /// let owned = Grid::from([[String::from("a")], [String::from("b")]]);
+    /// assert_eq!(owned.get(&owned.pos_at(1, 0).unwrap()).map(String::as_str), Some("b"));
+    /// ```
+    fn from(rows: [[T; W]; H]) -> Self {
+        Grid::from_valid(W, rows.into_iter().flatten().collect())
+    }
+}
 
-        assert!(
-            all_widths_same,
-            "Malformed grid! Not all rows have to same width!"
-        );
+impl<T, const W: usize, const H: usize> TryFrom<Grid<T>> for [[T; W]; H] {
+    type Error = GridError;
 
-        let data: Vec<T> = data.iter().flat_map(|slice| slice.to_vec()).collect();
-        Grid {
-            data,
-            width: *first_width,
+    /// Moves the values of a grid with width `W` and height `H` into an array of rows
+    ///
+    /// # Errors
+    ///
+    /// When the grid has a different shape (`GridError::ShapeMismatch`, as `(width, height)`)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let grid = Grid::new(2, vec![1, 2, 3, 4, 5, 6]);
+    /// let rows: [[i32; 2]; 3] = grid.try_into().unwrap();
+    /// assert_eq!(rows, [[1, 2], [3, 4], [5, 6]]);
+    ///
+    /// let grid = Grid::new(2, vec![1, 2, 3, 4, 5, 6]);
+    /// let wrong: Result<[[i32; 3]; 2], _> = grid.try_into();
+    /// assert_eq!(wrong, Err(GridError::ShapeMismatch { left: (2, 3), right: (3, 2) }));
+    /// ```
+    fn try_from(grid: Grid<T>) -> Result<Self, Self::Error> {
+        if grid.width() != W || grid.height() != H || grid.size() != W * H {
+            return Err(GridError::ShapeMismatch {
+                left: (grid.width(), grid.height()),
+                right: (W, H),
+            });
         }
+        let mut values = grid.data.into_iter();
+        Ok(std::array::from_fn(|_| {
+            std::array::from_fn(|_| values.next().expect("grid has W * H values"))
+        }))
     }
 }
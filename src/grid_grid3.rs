@@ -0,0 +1,356 @@
+use std::fmt::{Debug, Display};
+
+use super::grid_error::GridError;
+use super::grid_grid::Grid;
+
+/// A cell of a `Grid3`, as the index into its values: layer by layer, each layer in row-major order
+///
+/// # Example
+///
+/// ```
+/// # use grid::*;
+/// let cube = Grid3::new(3, 2, (0..24).collect());
+/// let pos = cube.pos_at(1, 2, 3).unwrap();
+/// assert_eq!(pos.index(), 3 * 6 + 1 * 3 + 2);
+/// assert_eq!(cube.row_col_layer(&pos), Some((1, 2, 3)));
+/// assert_eq!(format!("{pos:?}"), "Grid3Pos(23)");
+/// ```
+#[must_use]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Grid3Pos {
+    pub(super) pos: usize,
+}
+
+impl Grid3Pos {
+    pub fn new(pos: usize) -> Self {
+        Self { pos }
+    }
+
+    /// The index of the position into the values of the grid
+    #[must_use]
+    pub fn index(&self) -> usize {
+        self.pos
+    }
+}
+
+impl From<usize> for Grid3Pos {
+    fn from(pos: usize) -> Self {
+        Grid3Pos::new(pos)
+    }
+}
+
+impl From<Grid3Pos> for usize {
+    fn from(pos: Grid3Pos) -> Self {
+        pos.pos
+    }
+}
+
+impl Debug for Grid3Pos {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Grid3Pos({})", self.pos)
+    }
+}
+
+impl Display for Grid3Pos {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.pos)
+    }
+}
+
+/// A three-dimensional grid: `depth` layers of `width` x `height` cells.
+/// Rows and columns mean the same as in `Grid`, every layer is one such grid
+///
+/// # Example
+///
+/// ```
+/// # use grid::*;
+/// let cube = Grid3::from_fn(2, 2, 2, |row, col, layer| (row, col, layer));
+/// let pos = cube.pos_at(0, 1, 1).unwrap();
+/// assert_eq!(cube.get(&pos), Some(&(0, 1, 1)));
+/// assert_eq!(cube.layer(1).unwrap(), Grid::from_fn(2, 2, |row, col| (row, col, 1)));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Grid3<T> {
+    data: Vec<T>,
+    width: usize,
+    height: usize,
+    depth: usize,
+}
+
+impl<T> Grid3<T> {
+    /// Creates a grid with layers of `width` x `height` cells from the data, layer by layer
+    ///
+    /// # Panics
+    ///
+    /// When the data does not fill complete layers, see `try_new`
+    pub fn new(width: usize, height: usize, data: Vec<T>) -> Self {
+        Self::try_new(width, height, data).unwrap_or_else(|error| panic!("Grid malformed! {error}"))
+    }
+
+    /// Creates a grid with layers of `width` x `height` cells from the data, layer by layer
+    ///
+    /// # Errors
+    ///
+    /// When the length of the data is not a multiple of the layer size,
+    /// or a layer has no cells but there is data
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// assert_eq!(Grid3::try_new(2, 3, vec![0; 12]).unwrap().depth(), 2);
+    /// assert_eq!(
+    ///     Grid3::try_new(2, 3, vec![0; 8]).err(),
+    ///     Some(GridError::IncompleteLayer { width: 2, height: 3, len: 8 }));
+    /// assert!(Grid3::try_new(0, 3, vec![0]).is_err());
+    /// assert_eq!(Grid3::<i32>::try_new(0, 3, vec![]).unwrap().size(), 0);
+    /// ```
+    pub fn try_new(width: usize, height: usize, data: Vec<T>) -> Result<Self, GridError> {
+        let incomplete = || GridError::IncompleteLayer {
+            width,
+            height,
+            len: data.len(),
+        };
+        let layer_size = width.checked_mul(height).ok_or_else(incomplete)?;
+        let depth = match layer_size {
+            0 if data.is_empty() => 0,
+            0 => return Err(incomplete()),
+            _ if !data.len().is_multiple_of(layer_size) => return Err(incomplete()),
+            _ => data.len() / layer_size,
+        };
+        Ok(Grid3 {
+            data,
+            width,
+            height,
+            depth,
+        })
+    }
+
+    /// Creates a grid with `f(row, col, layer)` as the value of every cell
+    ///
+    /// # Panics
+    ///
+    /// When the number of cells overflows `usize`
+    pub fn from_fn(
+        width: usize,
+        height: usize,
+        depth: usize,
+        mut f: impl FnMut(usize, usize, usize) -> T,
+    ) -> Self {
+        let size = Grid::<T>::cell_count(Grid::<T>::cell_count(width, height), depth);
+        let mut data = Vec::with_capacity(size);
+        for layer in 0..depth {
+            for row in 0..height {
+                for col in 0..width {
+                    data.push(f(row, col, layer));
+                }
+            }
+        }
+        Grid3 {
+            data,
+            width,
+            height,
+            depth,
+        }
+    }
+
+    /// Stacks grids of the same shape, the first grid becomes layer 0
+    ///
+    /// # Errors
+    ///
+    /// `Empty` without layers, `ShapeMismatch` when a layer does not have the shape of the first one
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let layers = vec![Grid::new(2, vec![1, 2]), Grid::new(2, vec![3, 4])];
+    /// let cube = Grid3::from_layers(layers.clone()).unwrap();
+    /// assert_eq!((cube.width(), cube.height(), cube.depth()), (2, 1, 2));
+    /// assert_eq!(cube.into_layers(), layers);
+    ///
+    /// assert_eq!(Grid3::<i32>::from_layers(vec![]).err(), Some(GridError::Empty));
+    /// assert_eq!(
+    ///     Grid3::from_layers(vec![Grid::new(2, vec![1, 2]), Grid::new(1, vec![3, 4])]).err(),
+    ///     Some(GridError::ShapeMismatch { left: (2, 1), right: (1, 2) }));
+    /// ```
+    pub fn from_layers(layers: Vec<Grid<T>>) -> Result<Self, GridError> {
+        let first = layers.first().ok_or(GridError::Empty)?;
+        let shape = (first.width(), first.height());
+        if let Some(layer) = layers
+            .iter()
+            .find(|layer| (layer.width(), layer.height()) != shape)
+        {
+            return Err(GridError::ShapeMismatch {
+                left: shape,
+                right: (layer.width(), layer.height()),
+            });
+        }
+        let depth = layers.len();
+        let data = layers
+            .into_iter()
+            .flat_map(|layer| layer.into_raw_parts().1)
+            .collect();
+        Ok(Grid3 {
+            data,
+            width: shape.0,
+            height: shape.1,
+            depth,
+        })
+    }
+
+    /// Splits the grid into its layers
+    pub fn into_layers(self) -> Vec<Grid<T>> {
+        let layer_size = self.layer_size();
+        let mut data = self.data;
+        let mut layers = Vec::with_capacity(self.depth);
+        for _ in 0..self.depth {
+            let rest = data.split_off(layer_size);
+            layers.push(Grid::from_valid(
+                self.width,
+                std::mem::replace(&mut data, rest),
+            ));
+        }
+        layers
+    }
+
+    /// A copy of the layer, `None` past the last layer
+    #[must_use]
+    pub fn layer(&self, layer: usize) -> Option<Grid<T>>
+    where
+        T: Clone,
+    {
+        self.layer_slice(layer)
+            .map(|values| Grid::from_valid(self.width, values.to_vec()))
+    }
+
+    /// The values of the layer in row-major order, `None` past the last layer
+    #[must_use]
+    pub fn layer_slice(&self, layer: usize) -> Option<&[T]> {
+        let layer_size = self.layer_size();
+        (layer < self.depth).then(|| &self.data[layer * layer_size..(layer + 1) * layer_size])
+    }
+
+    /// The position of the cell, `None` when outside of the grid
+    pub fn pos_at(&self, row: usize, col: usize, layer: usize) -> Option<Grid3Pos> {
+        if col >= self.width || row >= self.height || layer >= self.depth {
+            None
+        } else {
+            Some(Grid3Pos::new(
+                layer * self.layer_size() + row * self.width + col,
+            ))
+        }
+    }
+
+    /// The `(row, col, layer)` of the position, `None` when outside of the grid
+    #[must_use]
+    pub fn row_col_layer(&self, pos: &Grid3Pos) -> Option<(usize, usize, usize)> {
+        if !self.contains_pos(pos) {
+            return None;
+        }
+        let (layer, in_layer) = (pos.pos / self.layer_size(), pos.pos % self.layer_size());
+        Some((in_layer / self.width, in_layer % self.width, layer))
+    }
+
+    #[must_use]
+    pub fn contains_pos(&self, pos: &Grid3Pos) -> bool {
+        pos.pos < self.data.len()
+    }
+
+    /// Gets the neighbors (or None) of the position: Up, Right, Down, Left inside its layer,
+    /// followed by the same cell in the layer before and in the layer after
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let cube = Grid3::new(3, 2, vec![0; 24]);
+    /// let pos = |row, col, layer| cube.pos_at(row, col, layer).unwrap();
+    /// assert_eq!(
+    ///     cube.get_neighbors(&pos(1, 1, 2)),
+    ///     [Some(pos(0, 1, 2)), Some(pos(1, 2, 2)), None, Some(pos(1, 0, 2)), Some(pos(1, 1, 1)), Some(pos(1, 1, 3))]);
+    /// // the corner touches the faces up, left and front
+    /// assert_eq!(
+    ///     cube.get_neighbors(&pos(0, 0, 0)),
+    ///     [None, Some(pos(0, 1, 0)), Some(pos(1, 0, 0)), None, None, Some(pos(0, 0, 1))]);
+    /// // the opposite corner touches the faces right, down and back
+    /// assert_eq!(
+    ///     cube.get_neighbors(&pos(1, 2, 3)),
+    ///     [Some(pos(0, 2, 3)), None, None, Some(pos(1, 1, 3)), Some(pos(1, 2, 2)), None]);
+    /// assert_eq!(cube.get_neighbors(&Grid3Pos::new(24)), [None; 6]);
+    /// ```
+    pub fn get_neighbors(&self, position: &Grid3Pos) -> [Option<Grid3Pos>; 6] {
+        let Some((row, col, layer)) = self.row_col_layer(position) else {
+            return [None; 6];
+        };
+        [
+            row.checked_sub(1)
+                .and_then(|row| self.pos_at(row, col, layer)),
+            self.pos_at(row, col + 1, layer),
+            self.pos_at(row + 1, col, layer),
+            col.checked_sub(1)
+                .and_then(|col| self.pos_at(row, col, layer)),
+            layer
+                .checked_sub(1)
+                .and_then(|layer| self.pos_at(row, col, layer)),
+            self.pos_at(row, col, layer + 1),
+        ]
+    }
+
+    #[must_use]
+    pub fn get(&self, pos: &Grid3Pos) -> Option<&T> {
+        self.data.get(pos.pos)
+    }
+
+    #[must_use]
+    pub fn get_mut(&mut self, pos: &Grid3Pos) -> Option<&mut T> {
+        self.data.get_mut(pos.pos)
+    }
+
+    /// Places a new value at the position, nothing happens outside of the grid
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let mut cube = Grid3::new(2, 2, vec![0; 8]);
+    /// cube.put(&cube.pos_at(1, 1, 1).unwrap(), 7);
+    /// cube.put(&Grid3Pos::new(8), 9);
+    /// assert_eq!(cube.iter().sum::<i32>(), 7);
+    /// ```
+    pub fn put(&mut self, pos: &Grid3Pos, new_value: T) {
+        if let Some(cell) = self.get_mut(pos) {
+            *cell = new_value;
+        }
+    }
+
+    /// The values layer by layer, each layer in row-major order
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.data.iter()
+    }
+
+    #[must_use]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    #[must_use]
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    #[must_use]
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    #[must_use]
+    pub fn size(&self) -> usize {
+        self.data.len()
+    }
+
+    fn layer_size(&self) -> usize {
+        self.width * self.height
+    }
+}
@@ -0,0 +1,95 @@
+use super::grid_grid::Grid;
+use super::grid_pos::GridPos;
+
+/// An undo token of a single mutation, see `Grid::put_recorded` and `Grid::swap_recorded`
+#[must_use = "a dropped change can no longer be reverted"]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Change<T> {
+    /// `old_value` was replaced at `pos`
+    Put { pos: GridPos, old_value: T },
+    /// The values at `a` and `b` were swapped
+    Swap { a: GridPos, b: GridPos },
+}
+
+impl<T> Grid<T> {
+    /// Places a new value at the position like `put`, and returns the change to undo it with `revert`.
+    /// There is no change when the position is outside of the grid
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let mut grid = Grid::new(2, vec![1, 2, 3, 4]);
+    /// let change = grid.put_recorded(&GridPos::new(1), 20).unwrap();
+    /// assert_eq!(change, Change::Put { pos: GridPos::new(1), old_value: 2 });
+    /// assert_eq!(grid, Grid::new(2, vec![1, 20, 3, 4]));
+    ///
+    /// grid.revert(change);
+    /// assert_eq!(grid, Grid::new(2, vec![1, 2, 3, 4]));
+    /// assert_eq!(grid.put_recorded(&GridPos::new(4), 5), None);
+    /// ```
+    pub fn put_recorded(&mut self, pos: &GridPos, new_value: T) -> Option<Change<T>> {
+        let old_value = std::mem::replace(self.get_mut(pos)?, new_value);
+        Some(Change::Put {
+            pos: *pos,
+            old_value,
+        })
+    }
+
+    /// Swaps the values at the positions like `swap`, and returns the change to undo it with `revert`.
+    /// There is no change when a position is outside of the grid
+    pub fn swap_recorded(&mut self, a: &GridPos, b: &GridPos) -> Option<Change<T>> {
+        self.swap(a, b).then_some(Change::Swap { a: *a, b: *b })
+    }
+
+    /// Undoes a change. Changes only restore the prior state when they are reverted
+    /// in the reverse order they were made in, see `revert_all`
+    ///
+    /// # Panics
+    ///
+    /// When the change is from a grid with more cells
+    pub fn revert(&mut self, change: Change<T>) {
+        match change {
+            Change::Put { pos, old_value } => self.data[pos.pos] = old_value,
+            Change::Swap { a, b } => self.data.swap(a.pos, b.pos),
+        }
+    }
+
+    /// Undoes the changes from the last to the first, for backtracking without cloning the grid
+    ///
+    /// # Panics
+    ///
+    /// When a change is from a grid with more cells
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let original = Grid::new(3, vec![0; 6]);
+    /// let mut grid = original.clone();
+    /// let pos = |row, col| grid.pos_at(row, col).unwrap();
+    /// let (a, b, c) = (pos(0, 0), pos(0, 2), pos(1, 1));
+    ///
+    /// let mut outer = Vec::new();
+    /// outer.extend(grid.put_recorded(&a, 1));
+    /// outer.extend(grid.swap_recorded(&a, &b));
+    /// let placed = grid.clone();
+    ///
+    /// // a nested branch on top of the outer changes
+    /// let mut inner = Vec::new();
+    /// inner.extend(grid.put_recorded(&c, 2));
+    /// inner.extend(grid.put_recorded(&c, 3));
+    /// inner.extend(grid.swap_recorded(&b, &c));
+    /// assert_eq!(grid, Grid::new(3, vec![0, 0, 3, 0, 1, 0]));
+    ///
+    /// grid.revert_all(inner);
+    /// assert_eq!(grid, placed);
+    /// grid.revert_all(outer);
+    /// assert_eq!(grid, original);
+    /// ```
+    pub fn revert_all(&mut self, changes: Vec<Change<T>>) {
+        for change in changes.into_iter().rev() {
+            self.revert(change);
+        }
+    }
+}
@@ -0,0 +1,148 @@
+use image::{GenericImageView, GrayImage, ImageBuffer, Luma, Pixel, Rgb, RgbImage};
+
+use super::grid_grid::Grid;
+
+/// Images and grids map their axes as `x == col` and `y == row`:
+/// the pixel `(x, y)` is the cell at `pos_at(y, x)`, the image width is the grid width
+impl<T> Grid<T> {
+    /// Creates a grid with one cell per pixel, mapping every pixel through `f`.
+    /// The pixel `(x, y)` becomes the cell at `pos_at(y, x)`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// use image::{Rgb, RgbImage};
+    ///
+    /// // 3 pixels wide, 2 pixels high, with a wall at x = 2, y = 0
+    /// let mut maze = RgbImage::from_pixel(3, 2, Rgb([255, 255, 255]));
+    /// maze.put_pixel(2, 0, Rgb([0, 0, 0]));
+    ///
+    /// let grid = Grid::from_image_with(&maze, |pixel| pixel == Rgb([0, 0, 0]));
+    /// assert_eq!(grid.width(), 3);
+    /// assert_eq!(grid.height(), 2);
+    /// assert_eq!(grid.get(&grid.pos_at(0, 2).unwrap()), Some(&true));
+    /// assert_eq!(grid.count(&true), 1);
+    /// ```
+    pub fn from_image_with<I: GenericImageView>(
+        image: &I,
+        mut f: impl FnMut(I::Pixel) -> T,
+    ) -> Self {
+        let (width, height) = image.dimensions();
+        Grid::from_fn(width as usize, height as usize, |row, col| {
+            f(image.get_pixel(col as u32, row as u32))
+        })
+    }
+
+    /// Creates an image with one pixel per cell, mapping every cell through `f`.
+    /// The cell at `pos_at(row, col)` becomes the pixel `(col, row)`
+    ///
+    /// # Panics
+    ///
+    /// When the width or height does not fit into an `u32`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// use image::Rgb;
+    ///
+    /// /*
+    ///     1,2,3,
+    ///     4,5,6,
+    ///  */
+    /// let grid = Grid::new(3, vec![1, 2, 3, 4, 5, 6]);
+    /// let image = grid.to_image_with(|&value| Rgb([value * 10, 0, 0]));
+    /// assert_eq!(image.dimensions(), (3, 2));
+    /// assert_eq!(image.get_pixel(2, 0), &Rgb([30, 0, 0]));
+    /// assert_eq!(image.get_pixel(0, 1), &Rgb([40, 0, 0]));
+    /// ```
+    #[must_use]
+    pub fn to_image_with<P: Pixel>(
+        &self,
+        mut f: impl FnMut(&T) -> P,
+    ) -> ImageBuffer<P, Vec<P::Subpixel>> {
+        let width = u32::try_from(self.width()).expect("Grid width fits into an image");
+        let height = u32::try_from(self.height()).expect("Grid height fits into an image");
+        ImageBuffer::from_fn(width, height, |x, y| {
+            f(&self.data[y as usize * self.width + x as usize])
+        })
+    }
+}
+
+impl Grid<u8> {
+    /// Creates a grid of the brightness of every pixel.
+    /// The pixel `(x, y)` becomes the cell at `pos_at(y, x)`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// use image::{GrayImage, Luma};
+    ///
+    /// let image = GrayImage::from_fn(2, 3, |x, y| Luma([(10 * y + x) as u8]));
+    /// let grid = Grid::from_luma(&image);
+    /// assert_eq!(grid.width(), 2);
+    /// assert_eq!(grid.height(), 3);
+    /// assert_eq!(grid.get(&grid.pos_at(2, 1).unwrap()), Some(&21));
+    /// assert_eq!(grid.to_luma(), image);
+    /// ```
+    pub fn from_luma(image: &GrayImage) -> Self {
+        Grid::from_image_with(image, |Luma([value])| value)
+    }
+
+    /// Creates a grayscale image with the cells as brightness.
+    /// The cell at `pos_at(row, col)` becomes the pixel `(col, row)`
+    ///
+    /// # Panics
+    ///
+    /// When the width or height does not fit into an `u32`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// use image::Luma;
+    ///
+    /// let grid = Grid::new(2, vec![0, 64, 128, 255]);
+    /// let image = grid.to_luma();
+    /// assert_eq!(image.get_pixel(1, 0), &Luma([64]));
+    /// assert_eq!(image.get_pixel(0, 1), &Luma([128]));
+    /// ```
+    #[must_use]
+    pub fn to_luma(&self) -> GrayImage {
+        self.to_image_with(|&value| Luma([value]))
+    }
+}
+
+impl Grid<[u8; 3]> {
+    /// Creates a grid of the `[red, green, blue]` channels of every pixel.
+    /// The pixel `(x, y)` becomes the cell at `pos_at(y, x)`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// use image::{Rgb, RgbImage};
+    ///
+    /// let mut image = RgbImage::new(4, 1);
+    /// image.put_pixel(3, 0, Rgb([1, 2, 3]));
+    /// let grid = Grid::from_rgb(&image);
+    /// assert_eq!(grid.get(&grid.pos_at(0, 3).unwrap()), Some(&[1, 2, 3]));
+    /// assert_eq!(grid.to_rgb(), image);
+    /// ```
+    pub fn from_rgb(image: &RgbImage) -> Self {
+        Grid::from_image_with(image, |Rgb(channels)| channels)
+    }
+
+    /// Creates a color image from the `[red, green, blue]` cells.
+    /// The cell at `pos_at(row, col)` becomes the pixel `(col, row)`
+    ///
+    /// # Panics
+    ///
+    /// When the width or height does not fit into an `u32`
+    #[must_use]
+    pub fn to_rgb(&self) -> RgbImage {
+        self.to_image_with(|&channels| Rgb(channels))
+    }
+}
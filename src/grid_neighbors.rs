@@ -0,0 +1,755 @@
+use super::grid_direction::Direction;
+use super::grid_grid::Grid;
+use super::grid_pos::GridPos;
+
+impl<T> Grid<T> {
+    /// Gets the Neighbor (or None) of the specified position in the given direction
+    ///
+    /// # Example
+    /// ```
+    /// # use grid::{Direction, Grid, GridPos};
+    /// let slices: &[&[i32]] = &[
+    ///     &[0, 1, 2],
+    ///     &[3, 4, 5],
+    ///     &[6, 7, 8]];
+    /// let grid = Grid::from(slices);
+    /// assert_eq!(grid.neighbor(&GridPos::new(4), Direction::UpLeft), Some(GridPos::new(0)));
+    /// assert_eq!(grid.neighbor(&GridPos::new(2), Direction::Right), None);
+    ///
+    /// // Edge cells, in the order of `get_neighbors`
+    /// # let pos_of = |i| Some(GridPos::new(i));
+    /// let on_edge = |i| Direction::ORTHOGONAL.map(|d| grid.neighbor(&GridPos::new(i), d));
+    /// assert_eq!(on_edge(1), [None, pos_of(2), pos_of(4), pos_of(0)]);
+    /// assert_eq!(on_edge(5), [pos_of(2), None, pos_of(8), pos_of(4)]);
+    /// assert_eq!(on_edge(7), [pos_of(4), pos_of(8), None, pos_of(6)]);
+    /// assert_eq!(on_edge(3), [pos_of(0), pos_of(4), pos_of(6), None]);
+    /// assert_eq!(on_edge(3), grid.get_neighbors(&GridPos::new(3)));
+    /// ```
+    #[must_use]
+    pub fn neighbor(&self, position: &GridPos, direction: Direction) -> Option<GridPos> {
+        let (d_row, d_col) = direction.offset();
+        self.offset(position, d_row, d_col)
+    }
+
+    /// Gets the 4 Neighbors of the specified position with named access,
+    /// the same positions as `get_neighbors`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let grid = Grid::new(3, (0..9).collect::<Vec<_>>());
+    /// let neighbors = grid.neighbors_of(&grid.pos_at(0, 1).unwrap());
+    /// assert_eq!(neighbors.up(), None);
+    /// assert_eq!(neighbors.right(), grid.pos_at(0, 2));
+    /// assert_eq!(neighbors.down(), grid.pos_at(1, 1));
+    /// assert_eq!(neighbors.left(), grid.pos_at(0, 0));
+    ///
+    /// let present: Vec<_> = neighbors.iter().map(|(direction, _)| direction).collect();
+    /// assert_eq!(present, vec![Direction::Right, Direction::Down, Direction::Left]);
+    /// ```
+    #[must_use]
+    pub fn neighbors_of(&self, position: &GridPos) -> Neighbors {
+        Neighbors {
+            positions: self.get_neighbors(position),
+        }
+    }
+
+    /// Gets the 8 Neighbors (or None) of the specified position, including diagonals.
+    /// First Neighbor is UP followed by the other 7 in a clockwise order
+    /// (Up, UpRight, Right, DownRight, Down, DownLeft, Left, UpLeft)
+    /// # Example
+    /// ```
+    /// # use grid::{Grid, GridPos};
+    /// let slices: &[&[i32]] = &[
+    ///     &[0, 1, 2],
+    ///     &[3, 4, 5],
+    ///     &[6, 7, 8]];
+    /// let grid = Grid::from(slices);
+    /// # let pos_of = |i| Some(GridPos::new(i));
+    /// assert_eq!(
+    ///     grid.get_neighbors8(&GridPos::new(4)),
+    ///     [pos_of(1), pos_of(2), pos_of(5), pos_of(8), pos_of(7), pos_of(6), pos_of(3), pos_of(0)]);
+    /// assert_eq!(
+    ///     grid.get_neighbors8(&GridPos::new(0)),
+    ///     [None, None, pos_of(1), pos_of(4), pos_of(3), None, None, None]);
+    /// // No wrapping into the next row
+    /// assert_eq!(
+    ///     grid.get_neighbors8(&GridPos::new(2)),
+    ///     [None, None, None, None, pos_of(5), pos_of(4), pos_of(1), None]);
+    /// assert_eq!(
+    ///     grid.get_neighbors8(&GridPos::new(6)),
+    ///     [pos_of(3), pos_of(4), pos_of(7), None, None, None, None, None]);
+    /// ```
+    #[must_use]
+    pub fn get_neighbors8(&self, position: &GridPos) -> [Option<GridPos>; 8] {
+        Direction::ALL.map(|direction| self.neighbor(position, direction))
+    }
+
+    /// Gets the 8 Neighbors (all Some(...)) of the specified position, including diagonals
+    ///
+    /// Calls `get_neighbors8(position).into_iter().flatten().collect::<Vec<GridPos>>();`
+    ///
+    /// # Example
+    /// ```
+    /// # use grid::{Grid, GridPos};
+    /// let slices: &[&[i32]] = &[
+    ///     &[0, 1, 2],
+    ///     &[3, 4, 5],
+    ///     &[6, 7, 8]];
+    /// let grid = Grid::from(slices);
+    /// assert_eq!(
+    ///     grid.get_neighbors8_flat(&GridPos::new(8)),
+    ///     vec![GridPos::new(5), GridPos::new(7), GridPos::new(4)]);
+    /// ```
+    #[must_use]
+    pub fn get_neighbors8_flat(&self, position: &GridPos) -> Vec<GridPos> {
+        self.get_neighbors8(position)
+            .into_iter()
+            .flatten()
+            .collect::<Vec<GridPos>>()
+    }
+
+    /// Gets the 4 diagonal Neighbors (or None) of the specified position.
+    /// First Neighbor is UP-RIGHT followed by the other 3 in a clockwise order
+    /// (UpRight, DownRight, DownLeft, UpLeft)
+    /// # Example
+    /// ```
+    /// # use grid::{Grid, GridPos};
+    /// let slices: &[&[i32]] = &[
+    ///     &[0, 1, 2],
+    ///     &[3, 4, 5],
+    ///     &[6, 7, 8]];
+    /// let grid = Grid::from(slices);
+    /// # let pos_of = |i| Some(GridPos::new(i));
+    /// assert_eq!(
+    ///     grid.get_neighbors_diagonal(&GridPos::new(4)),
+    ///     [pos_of(2), pos_of(8), pos_of(6), pos_of(0)]);
+    /// assert_eq!(
+    ///     grid.get_neighbors_diagonal(&GridPos::new(0)),
+    ///     [None, pos_of(4), None, None]);
+    /// assert_eq!(
+    ///     grid.get_neighbors_diagonal(&GridPos::new(2)),
+    ///     [None, None, pos_of(4), None]);
+    /// ```
+    #[must_use]
+    pub fn get_neighbors_diagonal(&self, position: &GridPos) -> [Option<GridPos>; 4] {
+        Direction::DIAGONAL.map(|direction| self.neighbor(position, direction))
+    }
+
+    /// Gets the 4 diagonal Neighbors (all Some(...)) of the specified position
+    ///
+    /// Calls `get_neighbors_diagonal(position).into_iter().flatten().collect::<Vec<GridPos>>();`
+    ///
+    /// # Example
+    /// ```
+    /// # use grid::{Grid, GridPos};
+    /// let slices: &[&[i32]] = &[
+    ///     &[0, 1, 2],
+    ///     &[3, 4, 5],
+    ///     &[6, 7, 8]];
+    /// let grid = Grid::from(slices);
+    /// assert_eq!(
+    ///     grid.get_neighbors_diagonal_flat(&GridPos::new(6)),
+    ///     vec![GridPos::new(4)]);
+    /// assert_eq!(
+    ///     grid.get_neighbors_diagonal_flat(&GridPos::new(4)),
+    ///     vec![GridPos::new(2), GridPos::new(8), GridPos::new(6), GridPos::new(0)]);
+    /// ```
+    #[must_use]
+    pub fn get_neighbors_diagonal_flat(&self, position: &GridPos) -> Vec<GridPos> {
+        self.get_neighbors_diagonal(position)
+            .into_iter()
+            .flatten()
+            .collect::<Vec<GridPos>>()
+    }
+
+    /// Gets the 4 Neighbors of the specified position on a torus: leaving the grid on one side
+    /// enters it again on the opposite side of the same row/column.
+    /// First Neighbor is UP followed by the other 3 in a clockwise order
+    /// # Panics
+    /// Panics, when the position is outside the grid
+    /// # Example
+    /// ```
+    /// # use grid::{Grid, GridPos};
+    /// let slices: &[&[i32]] = &[
+    ///     &[0, 1, 2],
+    ///     &[3, 4, 5],
+    ///     &[6, 7, 8],
+    ///     &[9, 10, 11]];
+    /// let grid = Grid::from(slices);
+    /// # let pos_of = GridPos::new;
+    /// assert_eq!(
+    ///     grid.get_neighbors_wrapping(&pos_of(4)),
+    ///     [pos_of(1), pos_of(5), pos_of(7), pos_of(3)]);
+    /// // Corners
+    /// assert_eq!(
+    ///     grid.get_neighbors_wrapping(&pos_of(0)),
+    ///     [pos_of(9), pos_of(1), pos_of(3), pos_of(2)]);
+    /// assert_eq!(
+    ///     grid.get_neighbors_wrapping(&pos_of(2)),
+    ///     [pos_of(11), pos_of(0), pos_of(5), pos_of(1)]);
+    /// assert_eq!(
+    ///     grid.get_neighbors_wrapping(&pos_of(9)),
+    ///     [pos_of(6), pos_of(10), pos_of(0), pos_of(11)]);
+    /// assert_eq!(
+    ///     grid.get_neighbors_wrapping(&pos_of(11)),
+    ///     [pos_of(8), pos_of(9), pos_of(2), pos_of(10)]);
+    /// ```
+    pub fn get_neighbors_wrapping(&self, position: &GridPos) -> [GridPos; 4] {
+        let (row, col) = self.row_col(position).expect("GridPos outside of the grid");
+        let height = self.height();
+        let width = self.width;
+
+        let up = (row + height - 1) % height;
+        let down = (row + 1) % height;
+        let left = (col + width - 1) % width;
+        let right = (col + 1) % width;
+
+        [
+            GridPos::new(up * width + col),
+            GridPos::new(row * width + right),
+            GridPos::new(down * width + col),
+            GridPos::new(row * width + left),
+        ]
+    }
+
+    /// Gets all positions within `radius` of the specified position (excluding the position itself)
+    /// in row-major order, clipped to the grid
+    /// # Example
+    /// ```
+    /// # use grid::{Grid, GridPos, Metric};
+    /// /*
+    ///     0, 1, 2, 3,
+    ///     4, 5, 6, 7,
+    ///     8, 9,10,11,
+    ///  */
+    /// let grid = Grid::new(4, (0..12).collect());
+    /// let values = |positions: Vec<GridPos>| -> Vec<i32> {
+    ///     positions.iter().map(|pos| *grid.get(pos).unwrap()).collect()
+    /// };
+    /// let pos_5 = grid.pos_at(1, 1).unwrap();
+    /// assert_eq!(
+    ///     values(grid.positions_within(&pos_5, 1, Metric::Manhattan)),
+    ///     vec![1, 4, 6, 9]);
+    /// assert_eq!(
+    ///     values(grid.positions_within(&pos_5, 1, Metric::Chebyshev)),
+    ///     vec![0, 1, 2, 4, 6, 8, 9, 10]);
+    /// assert_eq!(
+    ///     values(grid.positions_within(&pos_5, 2, Metric::Manhattan)),
+    ///     vec![0, 1, 2, 4, 6, 7, 8, 9, 10]);
+    ///
+    /// // Radius larger than the grid
+    /// let corner = grid.pos_at(2, 3).unwrap();
+    /// assert_eq!(
+    ///     values(grid.positions_within(&corner, 100, Metric::Chebyshev)),
+    ///     (0..11).collect::<Vec<_>>());
+    /// assert_eq!(grid.positions_within(&corner, 0, Metric::Manhattan), vec![]);
+    /// ```
+    #[must_use]
+    pub fn positions_within(
+        &self,
+        position: &GridPos,
+        radius: usize,
+        metric: Metric,
+    ) -> Vec<GridPos> {
+        let Some((row, col)) = self.row_col(position) else {
+            return Vec::new();
+        };
+        let first_row = row.saturating_sub(radius);
+        let last_row = row.saturating_add(radius).min(self.height - 1);
+        let first_col = col.saturating_sub(radius);
+        let last_col = col.saturating_add(radius).min(self.width - 1);
+
+        let mut positions = Vec::new();
+        for r in first_row..=last_row {
+            for c in first_col..=last_col {
+                let distance = metric.distance((row, col), (r, c));
+                if distance == 0 || distance > radius {
+                    continue;
+                }
+                if let Some(pos) = self.pos_at(r, c) {
+                    positions.push(pos);
+                }
+            }
+        }
+        positions
+    }
+
+    /// Gets all in-bounds destinations of a chess knight on the specified position.
+    /// Moves are ordered clockwise, starting with 2 up and 1 right
+    /// # Example
+    /// ```
+    /// # use grid::{Grid, GridPos};
+    /// /*
+    ///     0, 1, 2, 3, 4,
+    ///     5, 6, 7, 8, 9,
+    ///    10,11,12,13,14,
+    ///    15,16,17,18,19,
+    ///    20,21,22,23,24,
+    ///  */
+    /// let grid = Grid::new(5, (0..25).collect());
+    /// # let pos_of = GridPos::new;
+    /// // Center
+    /// assert_eq!(
+    ///     grid.knight_moves(&pos_of(12)),
+    ///     vec![pos_of(3), pos_of(9), pos_of(19), pos_of(23), pos_of(21), pos_of(15), pos_of(5), pos_of(1)]);
+    /// // Corner
+    /// assert_eq!(grid.knight_moves(&pos_of(0)), vec![pos_of(7), pos_of(11)]);
+    /// // Edge, no wrapping into the neighboring rows
+    /// assert_eq!(
+    ///     grid.knight_moves(&pos_of(9)),
+    ///     vec![pos_of(18), pos_of(12), pos_of(2)]);
+    /// ```
+    #[must_use]
+    pub fn knight_moves(&self, position: &GridPos) -> Vec<GridPos> {
+        const MOVES: [(isize, isize); 8] = [
+            (-2, 1),
+            (-1, 2),
+            (1, 2),
+            (2, 1),
+            (2, -1),
+            (1, -2),
+            (-1, -2),
+            (-2, -1),
+        ];
+        MOVES
+            .into_iter()
+            .filter_map(|(d_row, d_col)| self.offset(position, d_row, d_col))
+            .collect()
+    }
+
+    /// Gets the Neighbors (all Some(...)) of the specified position in the given `Neighborhood`
+    /// # Example
+    /// ```
+    /// # use grid::{Grid, GridPos, Neighborhood};
+    /// let grid = Grid::new(3, (0..9).collect::<Vec<i32>>());
+    /// # let pos_of = GridPos::new;
+    /// assert_eq!(grid.neighbors_in(&pos_of(0), Neighborhood::Orthogonal), vec![pos_of(1), pos_of(3)]);
+    /// assert_eq!(grid.neighbors_in(&pos_of(0), Neighborhood::Diagonal), vec![pos_of(4)]);
+    /// assert_eq!(grid.neighbors_in(&pos_of(0), Neighborhood::Moore), vec![pos_of(1), pos_of(4), pos_of(3)]);
+    /// ```
+    #[must_use]
+    pub fn neighbors_in(&self, position: &GridPos, neighborhood: Neighborhood) -> Vec<GridPos> {
+        neighborhood
+            .directions()
+            .iter()
+            .filter_map(|direction| self.neighbor(position, *direction))
+            .collect()
+    }
+
+    /// Gets the Neighbors in the given `Neighborhood` whose value matches `pred`
+    /// # Example
+    /// ```
+    /// # use grid::{Grid, GridPos, Neighborhood};
+    /// // Two cells touching only diagonally
+    /// let grid = Grid::from(vec![
+    ///     vec![1, 0],
+    ///     vec![0, 1]]);
+    /// let land = |cell: &i32| *cell == 1;
+    /// let top_left = GridPos::new(0);
+    /// assert_eq!(grid.neighbors_where_with(&top_left, Neighborhood::Orthogonal, land), vec![]);
+    /// assert_eq!(grid.neighbors_where_with(&top_left, Neighborhood::Moore, land), vec![GridPos::new(3)]);
+    /// assert_eq!(grid.count_neighbors_where_with(&top_left, Neighborhood::Diagonal, land), 1);
+    /// ```
+    #[must_use]
+    pub fn neighbors_where_with(
+        &self,
+        position: &GridPos,
+        neighborhood: Neighborhood,
+        pred: impl Fn(&T) -> bool,
+    ) -> Vec<GridPos> {
+        self.neighbors_in(position, neighborhood)
+            .into_iter()
+            .filter(|pos| self.get(pos).is_some_and(&pred))
+            .collect()
+    }
+
+    /// Counts the Neighbors in the given `Neighborhood` whose value matches `pred`
+    #[must_use]
+    pub fn count_neighbors_where_with(
+        &self,
+        position: &GridPos,
+        neighborhood: Neighborhood,
+        pred: impl Fn(&T) -> bool,
+    ) -> usize {
+        neighborhood
+            .directions()
+            .iter()
+            .filter_map(|direction| self.neighbor(position, *direction))
+            .filter(|pos| self.get(pos).is_some_and(&pred))
+            .count()
+    }
+
+    /// Gets the 4 Neighbors (in the order of `get_neighbors`) whose value matches `pred`
+    /// # Example
+    /// ```
+    /// # use grid::{Grid, GridPos};
+    /// let grid = Grid::from(vec![
+    ///     vec!['#', '.', '#'],
+    ///     vec!['.', '.', '.'],
+    ///     vec!['#', '#', '.']]);
+    /// let floor = |c: &char| *c == '.';
+    /// assert_eq!(
+    ///     grid.neighbors_where(&GridPos::new(4), floor),
+    ///     vec![GridPos::new(1), GridPos::new(5), GridPos::new(3)]);
+    /// assert_eq!(grid.count_neighbors_where(&GridPos::new(4), floor), 3);
+    /// // Missing neighbors on the edge don't count
+    /// assert_eq!(grid.count_neighbors_where(&GridPos::new(2), |_| true), 2);
+    /// ```
+    #[must_use]
+    pub fn neighbors_where(&self, position: &GridPos, pred: impl Fn(&T) -> bool) -> Vec<GridPos> {
+        self.neighbors_where_with(position, Neighborhood::Orthogonal, pred)
+    }
+
+    /// Counts the 4 Neighbors whose value matches `pred`
+    #[must_use]
+    pub fn count_neighbors_where(&self, position: &GridPos, pred: impl Fn(&T) -> bool) -> usize {
+        self.count_neighbors_where_with(position, Neighborhood::Orthogonal, pred)
+    }
+
+    /// Gets the 8 Neighbors (in the order of `get_neighbors8`) whose value matches `pred`
+    /// # Example
+    /// ```
+    /// # use grid::{Grid, GridPos};
+    /// let grid = Grid::from(vec![
+    ///     vec![1, 0, 1],
+    ///     vec![0, 1, 1],
+    ///     vec![1, 0, 0]]);
+    /// let alive = |cell: &i32| *cell == 1;
+    /// assert_eq!(grid.count_neighbors8_where(&GridPos::new(4), alive), 4);
+    /// assert_eq!(
+    ///     grid.neighbors8_where(&GridPos::new(4), alive),
+    ///     vec![GridPos::new(2), GridPos::new(5), GridPos::new(6), GridPos::new(0)]);
+    /// // Missing neighbors on the edge don't count
+    /// assert_eq!(grid.count_neighbors8_where(&GridPos::new(3), |_| true), 5);
+    /// assert_eq!(grid.count_neighbors8_where(&GridPos::new(8), alive), 2);
+    /// ```
+    #[must_use]
+    pub fn neighbors8_where(&self, position: &GridPos, pred: impl Fn(&T) -> bool) -> Vec<GridPos> {
+        self.neighbors_where_with(position, Neighborhood::Moore, pred)
+    }
+
+    /// Counts the 8 Neighbors whose value matches `pred`
+    #[must_use]
+    pub fn count_neighbors8_where(&self, position: &GridPos, pred: impl Fn(&T) -> bool) -> usize {
+        self.count_neighbors_where_with(position, Neighborhood::Moore, pred)
+    }
+
+    /// Gets all in-bounds positions with a Chebyshev distance of exactly `k` to the specified position.
+    ///
+    /// The ring is walked clockwise, starting with its top-left corner.
+    /// A ring of size 0 is the position itself
+    /// # Example
+    /// ```
+    /// # use grid::{Grid, GridPos};
+    /// /*
+    ///     0, 1, 2, 3,
+    ///     4, 5, 6, 7,
+    ///     8, 9,10,11,
+    ///    12,13,14,15,
+    ///  */
+    /// let grid = Grid::new(4, (0..16).collect());
+    /// let values = |positions: Vec<GridPos>| -> Vec<i32> {
+    ///     positions.iter().map(|pos| *grid.get(pos).unwrap()).collect()
+    /// };
+    /// let pos_5 = grid.pos_at(1, 1).unwrap();
+    /// assert_eq!(values(grid.ring(&pos_5, 0)), vec![5]);
+    /// assert_eq!(values(grid.ring(&pos_5, 1)), vec![0, 1, 2, 6, 10, 9, 8, 4]);
+    /// // Mostly clipped near the corner
+    /// assert_eq!(values(grid.ring(&pos_5, 2)), vec![3, 7, 11, 15, 14, 13, 12]);
+    /// assert_eq!(values(grid.ring(&pos_5, 3)), vec![]);
+    /// ```
+    #[must_use]
+    pub fn ring(&self, position: &GridPos, k: usize) -> Vec<GridPos> {
+        if k == 0 {
+            return self.get(position).map(|_| *position).into_iter().collect();
+        }
+        if !self.contains_pos(position) || k >= self.size() {
+            return Vec::new();
+        }
+        let k = k as isize;
+        let top = (-k..=k).map(|d_col| (-k, d_col));
+        let right = (-k + 1..=k).map(|d_row| (d_row, k));
+        let bottom = (-k..k).rev().map(|d_col| (k, d_col));
+        let left = (-k + 1..k).rev().map(|d_row| (d_row, -k));
+        top.chain(right)
+            .chain(bottom)
+            .chain(left)
+            .filter_map(|(d_row, d_col)| self.offset(position, d_row, d_col))
+            .collect()
+    }
+
+    /// Creates a new grid of the same shape by calling `f` for every cell with its position,
+    /// its value and the values of its 8 Neighbors (in the order of `get_neighbors8`).
+    ///
+    /// All calls see the values of `self`, results never feed back into the same pass
+    /// # Example
+    /// ```
+    /// # use grid::{Grid, GridPos};
+    /// // Game of Life, a blinker
+    /// let grid = Grid::from(vec![
+    ///     vec![false, false, false],
+    ///     vec![true, true, true],
+    ///     vec![false, false, false]]);
+    /// let step = |grid: &Grid<bool>| grid.map_neighborhood(|_pos, alive, neighbors| {
+    ///     let alive_neighbors = neighbors.iter().flatten().filter(|n| ***n).count();
+    ///     alive_neighbors == 3 || (*alive && alive_neighbors == 2)
+    /// });
+    /// let next = step(&grid);
+    /// assert_eq!(next.width(), 3);
+    /// assert_eq!(next.size(), 9);
+    /// assert_eq!(
+    ///     next.iter().copied().collect::<Vec<_>>(),
+    ///     vec![false, true, false, false, true, false, false, true, false]);
+    /// assert_eq!(
+    ///     step(&next).iter().collect::<Vec<_>>(),
+    ///     grid.iter().collect::<Vec<_>>());
+    /// ```
+    pub fn map_neighborhood<U>(&self, f: impl Fn(&GridPos, &T, &[Option<&T>; 8]) -> U) -> Grid<U> {
+        let data = self
+            .data
+            .iter()
+            .enumerate()
+            .map(|(index, value)| {
+                let pos = GridPos::new(index);
+                let neighbors = self
+                    .get_neighbors8(&pos)
+                    .map(|neighbor| neighbor.and_then(|neighbor| self.get(&neighbor)));
+                f(&pos, value, &neighbors)
+            })
+            .collect();
+        Grid::new(self.width, data)
+    }
+
+    /// Walks from `start` (excluded) in `direction` and collects the positions until the edge
+    /// of the grid or the first cell that is `blocked` (excluded)
+    /// # Example
+    /// ```
+    /// # use grid::{Direction, Grid, GridPos};
+    /// let grid = Grid::from(vec![
+    ///     vec!['.', '.', '.', '#'],
+    ///     vec!['.', '#', '.', '.'],
+    ///     vec!['.', '.', '.', '.']]);
+    /// let wall = |c: &char| *c == '#';
+    /// let start = grid.pos_at(1, 0).unwrap();
+    /// // Blocked immediately
+    /// assert_eq!(grid.cast_ray(&start, Direction::Right, wall), vec![]);
+    /// assert_eq!(grid.cast_ray_inclusive(&start, Direction::Right, wall), vec![grid.pos_at(1, 1).unwrap()]);
+    /// // Leaving the grid
+    /// assert_eq!(
+    ///     grid.cast_ray(&start, Direction::Down, wall),
+    ///     vec![grid.pos_at(2, 0).unwrap()]);
+    /// // Diagonal
+    /// assert_eq!(
+    ///     grid.cast_ray(&grid.pos_at(2, 0).unwrap(), Direction::UpRight, wall),
+    ///     vec![]);
+    /// assert_eq!(
+    ///     grid.cast_ray(&grid.pos_at(2, 1).unwrap(), Direction::UpRight, wall),
+    ///     vec![grid.pos_at(1, 2).unwrap()]);
+    /// assert_eq!(
+    ///     grid.cast_ray_inclusive(&grid.pos_at(2, 1).unwrap(), Direction::UpRight, wall),
+    ///     vec![grid.pos_at(1, 2).unwrap(), grid.pos_at(0, 3).unwrap()]);
+    /// ```
+    #[must_use]
+    pub fn cast_ray(
+        &self,
+        start: &GridPos,
+        direction: Direction,
+        blocked: impl Fn(&T) -> bool,
+    ) -> Vec<GridPos> {
+        self.walk_ray(start, direction, blocked, false)
+    }
+
+    /// Same as `cast_ray` but includes the blocking cell, if the ray hit one
+    #[must_use]
+    pub fn cast_ray_inclusive(
+        &self,
+        start: &GridPos,
+        direction: Direction,
+        blocked: impl Fn(&T) -> bool,
+    ) -> Vec<GridPos> {
+        self.walk_ray(start, direction, blocked, true)
+    }
+
+    fn walk_ray(
+        &self,
+        start: &GridPos,
+        direction: Direction,
+        blocked: impl Fn(&T) -> bool,
+        include_blocking: bool,
+    ) -> Vec<GridPos> {
+        let mut ray = Vec::new();
+        let mut current = *start;
+        while let Some(next) = self.neighbor(&current, direction) {
+            if self.get(&next).is_some_and(&blocked) {
+                if include_blocking {
+                    ray.push(next);
+                }
+                break;
+            }
+            ray.push(next);
+            current = next;
+        }
+        ray
+    }
+
+    /// Number of orthogonal steps between two positions (or None, if either is outside the grid)
+    /// # Example
+    /// ```
+    /// # use grid::{Grid, GridPos};
+    /// let grid = Grid::new(4, vec![0; 12]);
+    /// // (0, 3) and (1, 0) are only one flat index apart
+    /// let a = grid.pos_at(0, 3).unwrap();
+    /// let b = grid.pos_at(1, 0).unwrap();
+    /// assert_eq!(grid.manhattan_distance(&a, &b), Some(4));
+    /// assert_eq!(grid.chebyshev_distance(&a, &b), Some(3));
+    /// assert_eq!(grid.euclidean_distance_sq(&a, &b), Some(10));
+    /// assert_eq!(grid.manhattan_distance(&a, &a), Some(0));
+    /// let c = grid.pos_at(2, 1).unwrap();
+    /// assert_eq!(grid.chebyshev_distance(&a, &c), Some(2));
+    /// assert_eq!(grid.manhattan_distance(&a, &GridPos::new(12)), None);
+    /// ```
+    #[must_use]
+    pub fn manhattan_distance(&self, a: &GridPos, b: &GridPos) -> Option<usize> {
+        Some(Metric::Manhattan.distance(self.row_col(a)?, self.row_col(b)?))
+    }
+
+    /// Number of steps between two positions when diagonal steps are allowed
+    /// (or None, if either is outside the grid)
+    #[must_use]
+    pub fn chebyshev_distance(&self, a: &GridPos, b: &GridPos) -> Option<usize> {
+        Some(Metric::Chebyshev.distance(self.row_col(a)?, self.row_col(b)?))
+    }
+
+    /// Squared straight-line distance between two positions
+    /// (or None, if either is outside the grid)
+    #[must_use]
+    pub fn euclidean_distance_sq(&self, a: &GridPos, b: &GridPos) -> Option<usize> {
+        let (row_a, col_a) = self.row_col(a)?;
+        let (row_b, col_b) = self.row_col(b)?;
+        let d_row = row_a.abs_diff(row_b);
+        let d_col = col_a.abs_diff(col_b);
+        Some(d_row * d_row + d_col * d_col)
+    }
+}
+
+/// How the distance between two cells is measured
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Metric {
+    /// Number of orthogonal steps, a radius describes a diamond
+    Manhattan,
+    /// Number of steps when diagonal steps are allowed, a radius describes a square
+    Chebyshev,
+}
+
+impl Metric {
+    /// Distance between two `(row, col)` coordinates
+    #[must_use]
+    pub fn distance(self, (row_a, col_a): (usize, usize), (row_b, col_b): (usize, usize)) -> usize {
+        let d_row = row_a.abs_diff(row_b);
+        let d_col = col_a.abs_diff(col_b);
+        match self {
+            Metric::Manhattan => d_row + d_col,
+            Metric::Chebyshev => d_row.max(d_col),
+        }
+    }
+}
+
+/// Which cells count as Neighbors of a cell
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Neighborhood {
+    /// The 4 cells sharing an edge (Up, Right, Down, Left)
+    #[default]
+    Orthogonal,
+    /// The 4 cells sharing only a corner
+    Diagonal,
+    /// All 8 surrounding cells
+    Moore,
+}
+
+impl Neighborhood {
+    /// The directions of the Neighbors, clockwise
+    #[must_use]
+    pub fn directions(self) -> &'static [Direction] {
+        match self {
+            Neighborhood::Orthogonal => &Direction::ORTHOGONAL,
+            Neighborhood::Diagonal => &Direction::DIAGONAL,
+            Neighborhood::Moore => &Direction::ALL,
+        }
+    }
+}
+
+/// The 4 orthogonal Neighbors of a cell, see `Grid::neighbors_of`.
+///
+/// Neighbors are always in the order Up, Right, Down, Left (`Direction::ORTHOGONAL`),
+/// also when converted into the array that `Grid::get_neighbors` returns
+///
+/// # Example
+///
+/// ```
+/// # use grid::*;
+/// let grid = Grid::new(2, vec![0; 4]);
+/// let pos = grid.pos_at(1, 1).unwrap();
+/// let neighbors = grid.neighbors_of(&pos);
+/// assert_eq!(neighbors.get(Direction::Up), neighbors.up());
+/// assert_eq!(neighbors.get(Direction::UpLeft), None);
+/// assert_eq!(
+///     neighbors.iter().collect::<Vec<_>>(),
+///     vec![(Direction::Up, GridPos::new(1)), (Direction::Left, GridPos::new(2))]);
+///
+/// let array: [Option<GridPos>; 4] = neighbors.into();
+/// assert_eq!(array, grid.get_neighbors(&pos));
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct Neighbors {
+    positions: [Option<GridPos>; 4],
+}
+
+impl Neighbors {
+    #[must_use]
+    pub fn up(&self) -> Option<GridPos> {
+        self.positions[0]
+    }
+
+    #[must_use]
+    pub fn right(&self) -> Option<GridPos> {
+        self.positions[1]
+    }
+
+    #[must_use]
+    pub fn down(&self) -> Option<GridPos> {
+        self.positions[2]
+    }
+
+    #[must_use]
+    pub fn left(&self) -> Option<GridPos> {
+        self.positions[3]
+    }
+
+    /// The Neighbor in the direction, None outside the grid and for diagonal directions
+    #[must_use]
+    pub fn get(&self, direction: Direction) -> Option<GridPos> {
+        match direction {
+            Direction::Up => self.up(),
+            Direction::Right => self.right(),
+            Direction::Down => self.down(),
+            Direction::Left => self.left(),
+            _ => None,
+        }
+    }
+
+    /// The Neighbors inside the grid, with their direction
+    pub fn iter(&self) -> impl Iterator<Item = (Direction, GridPos)> + '_ {
+        Direction::ORTHOGONAL
+            .into_iter()
+            .zip(self.positions)
+            .filter_map(|(direction, pos)| Some((direction, pos?)))
+    }
+}
+
+impl From<Neighbors> for [Option<GridPos>; 4] {
+    fn from(neighbors: Neighbors) -> Self {
+        neighbors.positions
+    }
+}
@@ -0,0 +1,140 @@
+use std::convert::Infallible;
+use std::str::FromStr;
+
+use super::grid_error::{GridError, ParseGridError};
+use super::grid_grid::Grid;
+
+impl FromStr for Grid<char> {
+    type Err = GridError;
+
+    /// Creates a grid with one row per line, trailing newlines and `\r\n` line endings are fine
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let grid: Grid<char> = "#..\n.#.\n".parse().unwrap();
+    /// assert_eq!(grid.width(), 3);
+    /// assert_eq!(grid.height(), 2);
+    /// assert_eq!(grid.get(&grid.pos_at(1, 1).unwrap()), Some(&'#'));
+    ///
+    /// let crlf: Grid<char> = "#..\r\n.#.\r\n".parse().unwrap();
+    /// assert_eq!(crlf.to_string(), grid.to_string());
+    ///
+    /// assert_eq!(
+    ///     "#..\n.#\n".parse::<Grid<char>>().err(),
+    ///     Some(GridError::RaggedRows { expected: 3, found: 2, row: 1 }));
+    /// assert_eq!("".parse::<Grid<char>>().err(), Some(GridError::Empty));
+    /// assert_eq!("\n".parse::<Grid<char>>().err(), Some(GridError::Empty));
+    /// ```
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Grid::parse_mapped(input, Ok::<char, Infallible>).map_err(|error| match error {
+            ParseGridError::Grid(error) => error,
+            ParseGridError::Cell { error, .. } => match error {},
+        })
+    }
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid with one row per line, mapping every character through `f`.
+    /// Trailing newlines and `\r\n` line endings are fine
+    ///
+    /// # Errors
+    ///
+    /// When the input has no cells (no lines or only empty ones), the lines have different lengths,
+    /// or `f` fails (reported with the row and column of the character)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// #[derive(Debug, PartialEq)]
+    /// enum Tile { Wall, Floor }
+    ///
+    /// let parse = |c| match c {
+    ///     '#' => Ok(Tile::Wall),
+    ///     '.' => Ok(Tile::Floor),
+    ///     other => Err(other),
+    /// };
+    /// let grid = Grid::parse_mapped("#.\n.#\n", parse).unwrap();
+    /// assert_eq!(grid.get(&grid.pos_at(0, 1).unwrap()), Some(&Tile::Floor));
+    ///
+    /// assert_eq!(
+    ///     Grid::parse_mapped("#.\n.x", parse).err(),
+    ///     Some(ParseGridError::Cell { row: 1, col: 1, error: 'x' }));
+    /// assert_eq!(
+    ///     Grid::parse_mapped("#.\n.", parse).err(),
+    ///     Some(ParseGridError::Grid(GridError::RaggedRows { expected: 2, found: 1, row: 1 })));
+    /// for empty in ["", "\n", "\n\n"] {
+    ///     assert_eq!(
+    ///         Grid::parse_mapped(empty, parse).err(),
+    ///         Some(ParseGridError::Grid(GridError::Empty)));
+    /// }
+    /// ```
+    pub fn parse_mapped<E>(
+        input: &str,
+        f: impl Fn(char) -> Result<T, E>,
+    ) -> Result<Grid<T>, ParseGridError<E>> {
+        let mut width = None;
+        let mut data = Vec::new();
+        for (row, line) in input.lines().enumerate() {
+            let start = data.len();
+            for (col, c) in line.chars().enumerate() {
+                let value = f(c).map_err(|error| ParseGridError::Cell { row, col, error })?;
+                data.push(value);
+            }
+            let found = data.len() - start;
+            match width {
+                None => width = Some(found),
+                Some(expected) if expected != found => {
+                    return Err(GridError::RaggedRows {
+                        expected,
+                        found,
+                        row,
+                    }
+                    .into());
+                }
+                Some(_) => {}
+            }
+        }
+        // Only empty lines ("\n") are no cells either
+        let width = width.filter(|&width| width > 0).ok_or(GridError::Empty)?;
+        Ok(Grid::from_valid(width, data))
+    }
+}
+
+impl Grid<u8> {
+    /// Creates a grid of single digits with one row per line, every ASCII digit becomes its value.
+    /// Trailing newlines and `\r\n` line endings are fine
+    ///
+    /// # Errors
+    ///
+    /// When the input has no cells (no lines or only empty ones), the lines have different lengths,
+    /// or there is a character that is not a digit (`GridError::NotADigit`)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let grid = Grid::parse_digits("2199\r\n3987\r\n").unwrap();
+    /// assert_eq!(grid.width(), 4);
+    /// assert_eq!(grid.height(), 2);
+    /// assert_eq!(grid.get(&grid.pos_at(1, 2).unwrap()), Some(&8));
+    ///
+    /// assert_eq!(
+    ///     Grid::parse_digits("2199\n39a7").err(),
+    ///     Some(GridError::NotADigit { row: 1, col: 2, found: 'a' }));
+    /// assert_eq!(Grid::parse_digits("").err(), Some(GridError::Empty));
+    /// ```
+    pub fn parse_digits(input: &str) -> Result<Self, GridError> {
+        let digit = |c: char| c.to_digit(10).and_then(|d| u8::try_from(d).ok()).ok_or(c);
+        Grid::parse_mapped(input, digit).map_err(|error| match error {
+            ParseGridError::Grid(error) => error,
+            ParseGridError::Cell { row, col, error } => GridError::NotADigit {
+                row,
+                col,
+                found: error,
+            },
+        })
+    }
+}
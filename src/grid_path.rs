@@ -0,0 +1,658 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+
+use super::grid_grid::Grid;
+use super::grid_neighbors::Neighborhood;
+use super::grid_pos::GridPos;
+
+impl<T> Grid<T> {
+    /// Gets a shortest path from `start` to `goal` (both included) over the `passable` cells,
+    /// using breadth-first search over the 4 Neighbors
+    /// (or None, if the goal is unreachable or either end is not passable)
+    ///
+    /// Calls `shortest_path_with(start, goal, Neighborhood::Orthogonal, passable)`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let maze = Grid::from(vec![
+    ///     "S.#...".chars().collect::<Vec<_>>(),
+    ///     ".##.#.".chars().collect(),
+    ///     "....#E".chars().collect()]);
+    /// let start = maze.position_of(&'S').unwrap();
+    /// let goal = maze.position_of(&'E').unwrap();
+    /// let floor = |c: &char| *c != '#';
+    /// let path = maze.shortest_path(&start, &goal, floor).unwrap();
+    /// assert_eq!(path.len(), 12);
+    /// assert_eq!(path.first(), Some(&start));
+    /// assert_eq!(path.last(), Some(&goal));
+    ///
+    /// assert_eq!(maze.shortest_path(&start, &start, floor), Some(vec![start]));
+    /// let wall = maze.pos_at(0, 2).unwrap();
+    /// assert_eq!(maze.shortest_path(&start, &wall, floor), None);
+    /// let blocked = |c: &char| *c == '.' || *c == 'S';
+    /// assert_eq!(maze.shortest_path(&start, &goal, blocked), None);
+    /// ```
+    #[must_use]
+    pub fn shortest_path(
+        &self,
+        start: &GridPos,
+        goal: &GridPos,
+        passable: impl Fn(&T) -> bool,
+    ) -> Option<Vec<GridPos>> {
+        self.shortest_path_with(start, goal, Neighborhood::Orthogonal, passable)
+    }
+
+    /// Same as `shortest_path`, moving through the given `Neighborhood`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let grid = Grid::new(3, vec![0; 9]);
+    /// let path = grid
+    ///     .shortest_path_with(&GridPos::new(0), &GridPos::new(8), Neighborhood::Moore, |_| true)
+    ///     .unwrap();
+    /// assert_eq!(path, vec![GridPos::new(0), GridPos::new(4), GridPos::new(8)]);
+    /// ```
+    #[must_use]
+    pub fn shortest_path_with(
+        &self,
+        start: &GridPos,
+        goal: &GridPos,
+        neighborhood: Neighborhood,
+        passable: impl Fn(&T) -> bool,
+    ) -> Option<Vec<GridPos>> {
+        if !self.get(start).is_some_and(&passable) || !self.get(goal).is_some_and(&passable) {
+            return None;
+        }
+        let mut parents: Vec<Option<GridPos>> = vec![None; self.size()];
+        let mut visited = vec![false; self.size()];
+        let mut queue = VecDeque::from([*start]);
+        visited[start.pos] = true;
+        while let Some(current) = queue.pop_front() {
+            if current == *goal {
+                return Some(Self::reconstruct_path(&parents, current));
+            }
+            for next in self.neighbors_in(&current, neighborhood) {
+                if !visited[next.pos] && passable(&self.data[next.pos]) {
+                    visited[next.pos] = true;
+                    parents[next.pos] = Some(current);
+                    queue.push_back(next);
+                }
+            }
+        }
+        None
+    }
+
+    /// Counts the shortest paths from `start` to `goal` over the `passable` cells and the 4 Neighbors,
+    /// without building them. The count saturates at `u64::MAX`
+    /// (or None, if the goal is unreachable or either end is not passable)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// // Corner to corner on an open grid: choose which 3 of the 6 steps go down
+    /// let open = Grid::new(4, vec!['.'; 16]);
+    /// let floor = |c: &char| *c == '.';
+    /// assert_eq!(open.shortest_path_count(&GridPos::new(0), &GridPos::new(15), floor), Some(20));
+    /// assert_eq!(open.shortest_path_count(&GridPos::new(5), &GridPos::new(5), floor), Some(1));
+    ///
+    /// let walled = Grid::new(3, vec!['.', '#', '.', '.', '#', '.']);
+    /// assert_eq!(walled.shortest_path_count(&GridPos::new(0), &GridPos::new(2), floor), None);
+    /// ```
+    #[must_use]
+    pub fn shortest_path_count(
+        &self,
+        start: &GridPos,
+        goal: &GridPos,
+        passable: impl Fn(&T) -> bool,
+    ) -> Option<u64> {
+        let mut counts = vec![0_u64; self.size()];
+        self.shortest_path_levels(start, goal, passable, |current, next| {
+            let via = if current == start {
+                1
+            } else {
+                counts[current.pos]
+            };
+            counts[next.pos] = counts[next.pos].saturating_add(via);
+        })?;
+        Some(if goal == start { 1 } else { counts[goal.pos] })
+    }
+
+    /// Gets every shortest path from `start` to `goal` (both included) over the `passable` cells
+    /// and the 4 Neighbors, at most `limit` of them. Empty if the goal is unreachable or either end
+    /// is not passable
+    ///
+    /// The number of shortest paths grows exponentially with their length (see `shortest_path_count`),
+    /// so keep the `limit` low on open grids
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let open = Grid::new(3, vec!['.'; 9]);
+    /// let floor = |c: &char| *c == '.';
+    /// let paths = open.all_shortest_paths(&GridPos::new(0), &GridPos::new(8), floor, usize::MAX);
+    /// assert_eq!(paths.len(), 6);
+    /// assert!(paths.iter().all(|path| path.len() == 5));
+    /// assert!(paths.iter().all(|path| path[0] == GridPos::new(0) && path[4] == GridPos::new(8)));
+    /// assert_eq!(
+    ///     paths[0],
+    ///     vec![0, 1, 2, 5, 8].into_iter().map(GridPos::new).collect::<Vec<_>>());
+    ///
+    /// assert_eq!(open.all_shortest_paths(&GridPos::new(0), &GridPos::new(8), floor, 2).len(), 2);
+    ///
+    /// let maze = Grid::new(3, vec![
+    ///     '.', '.', '.',
+    ///     '.', '#', '.',
+    ///     '.', '.', '.']);
+    /// let around = maze.all_shortest_paths(&GridPos::new(3), &GridPos::new(5), floor, 10);
+    /// assert_eq!(around.len(), 2);
+    /// assert_eq!(maze.all_shortest_paths(&GridPos::new(0), &GridPos::new(4), floor, 10), Vec::<Vec<_>>::new());
+    /// ```
+    #[must_use]
+    pub fn all_shortest_paths(
+        &self,
+        start: &GridPos,
+        goal: &GridPos,
+        passable: impl Fn(&T) -> bool,
+        limit: usize,
+    ) -> Vec<Vec<GridPos>> {
+        let mut parents: Vec<Vec<GridPos>> = vec![Vec::new(); self.size()];
+        if self
+            .shortest_path_levels(start, goal, passable, |current, next| {
+                parents[next.pos].push(*current);
+            })
+            .is_none()
+        {
+            return Vec::new();
+        }
+        let mut paths = Vec::new();
+        // Walks back from the goal, with the parents still to try for every cell on the path
+        let mut stack = vec![(*goal, 0)];
+        while let Some(&mut (current, ref mut next_parent)) = stack.last_mut() {
+            if paths.len() == limit {
+                break;
+            }
+            if current == *start {
+                paths.push(stack.iter().rev().map(|(pos, _)| *pos).collect());
+                stack.pop();
+            } else if let Some(&parent) = parents[current.pos].get(*next_parent) {
+                *next_parent += 1;
+                stack.push((parent, 0));
+            } else {
+                stack.pop();
+            }
+        }
+        paths
+    }
+
+    /// Breadth-first search from `start` over the 4 Neighbors, which calls `on_shortest(current, next)`
+    /// for every step that is part of a shortest path to `next`, until all cells as far as `goal` are done
+    /// (or None, if the goal is unreachable or either end is not passable)
+    fn shortest_path_levels(
+        &self,
+        start: &GridPos,
+        goal: &GridPos,
+        passable: impl Fn(&T) -> bool,
+        mut on_shortest: impl FnMut(&GridPos, &GridPos),
+    ) -> Option<()> {
+        if !self.get(start).is_some_and(&passable) || !self.get(goal).is_some_and(&passable) {
+            return None;
+        }
+        let mut distances: Vec<Option<usize>> = vec![None; self.size()];
+        let mut queue = VecDeque::from([*start]);
+        distances[start.pos] = Some(0);
+        while let Some(current) = queue.pop_front() {
+            if current == *goal {
+                return Some(());
+            }
+            let next_distance = distances[current.pos].map(|distance| distance + 1);
+            for next in self.get_neighbors(&current).into_iter().flatten() {
+                if distances[next.pos].is_none() && passable(&self.data[next.pos]) {
+                    distances[next.pos] = next_distance;
+                    queue.push_back(next);
+                }
+                if distances[next.pos] == next_distance {
+                    on_shortest(&current, &next);
+                }
+            }
+        }
+        None
+    }
+
+    /// Follows the parents from `end` back to the start, returns the path from the start to `end`
+    pub(super) fn reconstruct_path(parents: &[Option<GridPos>], end: GridPos) -> Vec<GridPos> {
+        let mut path = vec![end];
+        let mut current = end;
+        while let Some(parent) = parents[current.pos] {
+            path.push(parent);
+            current = parent;
+        }
+        path.reverse();
+        path
+    }
+
+    /// Gets a cheapest path from `start` to `goal` (both included) and its total cost,
+    /// using A* over the 4 Neighbors (or None, if the goal is unreachable)
+    ///
+    /// `cost` is the cost of entering a cell, None marks the cell as impassable.
+    /// `heuristic` estimates the remaining cost to the goal, it must never overestimate
+    /// for the path to be optimal (see `manhattan_heuristic`)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// // Going straight through the 9s is shorter, but more expensive
+    /// let terrain = Grid::from(vec![
+    ///     vec![1, 1, 1, 1, 1],
+    ///     vec![1, 9, 9, 9, 1],
+    ///     vec![1, 1, 1, 1, 1]]);
+    /// let start = terrain.pos_at(1, 0).unwrap();
+    /// let goal = terrain.pos_at(1, 4).unwrap();
+    /// let (path, cost) = terrain
+    ///     .astar(&start, &goal, |_, v| Some(*v), terrain.manhattan_heuristic(&goal))
+    ///     .unwrap();
+    /// assert_eq!(cost, 6);
+    /// assert_eq!(path.len(), 7);
+    /// assert_eq!(path.first(), Some(&start));
+    /// assert_eq!(path.last(), Some(&goal));
+    ///
+    /// // A wall of impassable cells
+    /// let walled = Grid::from(vec![
+    ///     vec![Some(1), None, Some(1)],
+    ///     vec![Some(1), None, Some(1)]]);
+    /// let goal = walled.pos_at(0, 2).unwrap();
+    /// assert_eq!(walled.astar(&GridPos::new(0), &goal, |_, v| *v, |_| 0), None);
+    /// ```
+    #[must_use]
+    pub fn astar(
+        &self,
+        start: &GridPos,
+        goal: &GridPos,
+        cost: impl Fn(&GridPos, &T) -> Option<u32>,
+        heuristic: impl Fn(&GridPos) -> u32,
+    ) -> Option<(Vec<GridPos>, u32)> {
+        self.get(start).and_then(|value| cost(start, value))?;
+        self.astar_by(start, goal, |_, to| cost(to, &self.data[to.pos]), heuristic)
+    }
+
+    /// Same as `astar`, with `cost(from, from_value, to, to_value)` as the cost of moving between
+    /// two Neighbors, so that costs can depend on the direction of a step.
+    /// None marks the step as impossible, e.g. entering a one-way door from the wrong side
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// // '>' can only be entered moving right
+    /// let map = Grid::new(3, vec![
+    ///     '.', '>', '.',
+    ///     '.', '.', '.']);
+    /// let cost = |from: &GridPos, _: &char, to: &GridPos, &tile: &char| {
+    ///     (tile != '>' || to.index() == from.index() + 1).then_some(1)
+    /// };
+    /// let (a, b) = (map.pos_at(0, 0).unwrap(), map.pos_at(0, 2).unwrap());
+    ///
+    /// let (there, cost_there) = map.astar_edges(&a, &b, cost, |_| 0).unwrap();
+    /// assert_eq!(there, vec![a, map.pos_at(0, 1).unwrap(), b]);
+    /// assert_eq!(cost_there, 2);
+    ///
+    /// let (back, cost_back) = map.astar_edges(&b, &a, cost, |_| 0).unwrap();
+    /// assert_eq!(cost_back, 4);
+    /// assert!(!back.contains(&map.pos_at(0, 1).unwrap()));
+    ///
+    /// // Climbing costs the height difference on top of the step, descending is free
+    /// let heights = Grid::new(3, vec![0, 5, 9]);
+    /// let climb = |_: &GridPos, &from: &u32, _: &GridPos, &to: &u32| Some(1 + to.saturating_sub(from));
+    /// let (start, top) = (GridPos::new(0), GridPos::new(2));
+    /// assert_eq!(heights.astar_edges(&start, &top, climb, |_| 0).unwrap().1, 11);
+    /// assert_eq!(heights.astar_edges(&top, &start, climb, |_| 0).unwrap().1, 2);
+    /// ```
+    #[must_use]
+    pub fn astar_edges(
+        &self,
+        start: &GridPos,
+        goal: &GridPos,
+        cost: impl Fn(&GridPos, &T, &GridPos, &T) -> Option<u32>,
+        heuristic: impl Fn(&GridPos) -> u32,
+    ) -> Option<(Vec<GridPos>, u32)> {
+        self.astar_by(
+            start,
+            goal,
+            |from, to| cost(from, &self.data[from.pos], to, &self.data[to.pos]),
+            heuristic,
+        )
+    }
+
+    /// The Manhattan distance to `goal`, as a heuristic for `astar` with a minimum cost of 1 per step
+    pub fn manhattan_heuristic(&self, goal: &GridPos) -> impl Fn(&GridPos) -> u32 + '_ {
+        let goal = *goal;
+        move |pos: &GridPos| {
+            self.manhattan_distance(pos, &goal)
+                .map_or(u32::MAX, |distance| {
+                    u32::try_from(distance).unwrap_or(u32::MAX)
+                })
+        }
+    }
+
+    /// A* over the 4 Neighbors, where `step_cost` is the cost of moving between two Neighbors
+    pub(super) fn astar_by(
+        &self,
+        start: &GridPos,
+        goal: &GridPos,
+        step_cost: impl Fn(&GridPos, &GridPos) -> Option<u32>,
+        heuristic: impl Fn(&GridPos) -> u32,
+    ) -> Option<(Vec<GridPos>, u32)> {
+        if self.get(start).is_none() || self.get(goal).is_none() {
+            return None;
+        }
+        let mut best: Vec<Option<u32>> = vec![None; self.size()];
+        let mut settled = vec![false; self.size()];
+        let mut parents: Vec<Option<GridPos>> = vec![None; self.size()];
+        let mut open = BinaryHeap::new();
+
+        best[start.pos] = Some(0);
+        // Ties on the estimate prefer the cell closer to the goal, then the lower index
+        open.push(Reverse((heuristic(start), heuristic(start), start.pos)));
+        while let Some(Reverse((_, _, index))) = open.pop() {
+            if settled[index] {
+                continue;
+            }
+            settled[index] = true;
+            let current = GridPos::new(index);
+            let cost_so_far = best[index].expect("queued cells have a cost");
+            if current == *goal {
+                return Some((Self::reconstruct_path(&parents, current), cost_so_far));
+            }
+            for next in self.get_neighbors(&current).into_iter().flatten() {
+                if settled[next.pos] {
+                    continue;
+                }
+                let Some(step) = step_cost(&current, &next) else {
+                    continue;
+                };
+                let cost = cost_so_far.saturating_add(step);
+                if best[next.pos].is_none_or(|known| cost < known) {
+                    best[next.pos] = Some(cost);
+                    parents[next.pos] = Some(current);
+                    let remaining = heuristic(&next);
+                    open.push(Reverse((
+                        cost.saturating_add(remaining),
+                        remaining,
+                        next.pos,
+                    )));
+                }
+            }
+        }
+        None
+    }
+
+    /// Gets the minimum cost from any of the `sources` to every cell (Dijkstra),
+    /// None for unreachable cells
+    ///
+    /// `cost` is the cost of entering a cell, None marks the cell as impassable.
+    /// Sources have a cost of 0, impassable sources and sources outside the grid are ignored
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let grid = Grid::new(5, vec![1; 5]);
+    /// let sources = [GridPos::new(0), GridPos::new(4)];
+    /// let distances = grid.distance_map(&sources, |v| Some(*v));
+    /// assert_eq!(
+    ///     distances.iter().copied().collect::<Vec<_>>(),
+    ///     vec![Some(0), Some(1), Some(2), Some(1), Some(0)]);
+    ///
+    /// // A wall splitting the map
+    /// let walled = Grid::from(vec![
+    ///     vec!['.', '#', '.'],
+    ///     vec!['.', '#', '.']]);
+    /// let distances = walled.distance_map(&[GridPos::new(0)], |c| (*c == '.').then_some(1));
+    /// assert_eq!(
+    ///     distances.iter().copied().collect::<Vec<_>>(),
+    ///     vec![Some(0), None, None, Some(1), None, None]);
+    /// assert_eq!(distances.width(), 3);
+    /// ```
+    pub fn distance_map(
+        &self,
+        sources: &[GridPos],
+        cost: impl Fn(&T) -> Option<u32>,
+    ) -> Grid<Option<u32>> {
+        let mut distances: Vec<Option<u32>> = vec![None; self.size()];
+        let mut open = BinaryHeap::new();
+        for source in sources {
+            if self.get(source).and_then(&cost).is_some() {
+                distances[source.pos] = Some(0);
+                open.push(Reverse((0, source.pos)));
+            }
+        }
+        while let Some(Reverse((distance, index))) = open.pop() {
+            if distances[index].is_some_and(|known| known < distance) {
+                continue;
+            }
+            for next in self
+                .get_neighbors(&GridPos::new(index))
+                .into_iter()
+                .flatten()
+            {
+                let Some(step) = cost(&self.data[next.pos]) else {
+                    continue;
+                };
+                let next_distance = distance.saturating_add(step);
+                if distances[next.pos].is_none_or(|known| next_distance < known) {
+                    distances[next.pos] = Some(next_distance);
+                    open.push(Reverse((next_distance, next.pos)));
+                }
+            }
+        }
+        Grid::new(self.width, distances)
+    }
+
+    /// Gets every cell reachable from `start` over the 4 Neighbors with a total cost of at most `budget`,
+    /// in row-major order. The start is included at a cost of 0
+    ///
+    /// `cost` is the cost of entering a cell, None marks the cell as impassable.
+    /// Empty when the start is impassable or outside the grid
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// // 1 for plains, 3 for forest, None for water
+    /// let terrain = Grid::new(4, vec![
+    ///     Some(1), Some(3), Some(1), Some(1),
+    ///     Some(1), None,    Some(1), Some(1)]);
+    /// let start = terrain.pos_at(0, 0).unwrap();
+    /// let pos = |row, col| terrain.pos_at(row, col).unwrap();
+    ///
+    /// assert_eq!(terrain.reachable_within(&start, 0, |v| *v), vec![start]);
+    /// assert_eq!(terrain.reachable_within(&start, 2, |v| *v), vec![start, pos(1, 0)]);
+    /// // Exactly enough to enter the forest
+    /// assert_eq!(terrain.reachable_within(&start, 3, |v| *v), vec![start, pos(0, 1), pos(1, 0)]);
+    /// assert_eq!(
+    ///     terrain.reachable_within(&start, 5, |v| *v),
+    ///     vec![start, pos(0, 1), pos(0, 2), pos(0, 3), pos(1, 0), pos(1, 2)]);
+    ///
+    /// assert_eq!(terrain.reachable_within(&pos(1, 1), 5, |v| *v), vec![]);
+    /// ```
+    #[must_use]
+    pub fn reachable_within(
+        &self,
+        start: &GridPos,
+        budget: u32,
+        cost: impl Fn(&T) -> Option<u32>,
+    ) -> Vec<GridPos> {
+        if self.get(start).and_then(&cost).is_none() {
+            return Vec::new();
+        }
+        let mut best: Vec<Option<u32>> = vec![None; self.size()];
+        let mut reached = Vec::new();
+        let mut open = BinaryHeap::from([Reverse((0, start.pos))]);
+        best[start.pos] = Some(0);
+        while let Some(Reverse((spent, index))) = open.pop() {
+            if best[index].is_some_and(|known| known < spent) {
+                continue;
+            }
+            reached.push(GridPos::new(index));
+            for next in self
+                .get_neighbors(&GridPos::new(index))
+                .into_iter()
+                .flatten()
+            {
+                let Some(step) = cost(&self.data[next.pos]) else {
+                    continue;
+                };
+                let next_spent = spent.saturating_add(step);
+                if next_spent <= budget && best[next.pos].is_none_or(|known| next_spent < known) {
+                    best[next.pos] = Some(next_spent);
+                    open.push(Reverse((next_spent, next.pos)));
+                }
+            }
+        }
+        reached.sort();
+        reached
+    }
+
+    /// Assigns every cell the index into `sources` of its nearest source by BFS distance over the
+    /// 4 Neighbors, moving only through `passable` cells
+    ///
+    /// Cells at the same distance from two or more sources belong to none of them, as do unreachable
+    /// and impassable cells. Impassable sources and sources outside the grid are ignored,
+    /// sources on the same cell tie it
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let grid = Grid::new(5, vec!['.'; 10]);
+    /// let sources = [grid.pos_at(0, 0).unwrap(), grid.pos_at(0, 4).unwrap()];
+    /// let owners = grid.partition_by_nearest(&sources, |&c| c == '.');
+    /// // The middle column is as far from both sources
+    /// assert_eq!(owners, Grid::new(5, vec![
+    ///     Some(0), Some(0), None, Some(1), Some(1),
+    ///     Some(0), Some(0), None, Some(1), Some(1)]));
+    ///
+    /// // A source walled off from the others only gets its own room
+    /// let map = Grid::new(4, vec![
+    ///     '.', '.', '#', '.',
+    ///     '.', '.', '#', '.']);
+    /// let sources = [map.pos_at(1, 1).unwrap(), map.pos_at(0, 3).unwrap(), map.pos_at(0, 0).unwrap()];
+    /// let owners = map.partition_by_nearest(&sources, |&c| c == '.');
+    /// assert_eq!(owners, Grid::new(4, vec![
+    ///     Some(2), None, None, Some(1),
+    ///     None, Some(0), None, Some(1)]));
+    /// ```
+    pub fn partition_by_nearest(
+        &self,
+        sources: &[GridPos],
+        passable: impl Fn(&T) -> bool,
+    ) -> Grid<Option<usize>> {
+        let mut distances: Vec<Option<u32>> = vec![None; self.size()];
+        let mut owners: Vec<Option<usize>> = vec![None; self.size()];
+        let mut queue = VecDeque::new();
+        for (index, source) in sources.iter().enumerate() {
+            if !self.get(source).is_some_and(&passable) {
+                continue;
+            }
+            if distances[source.pos].is_some() {
+                owners[source.pos] = None;
+            } else {
+                distances[source.pos] = Some(0);
+                owners[source.pos] = Some(index);
+                queue.push_back(*source);
+            }
+        }
+        while let Some(current) = queue.pop_front() {
+            let distance = distances[current.pos].map(|distance| distance + 1);
+            let owner = owners[current.pos];
+            for next in self.get_neighbors(&current).into_iter().flatten() {
+                if distances[next.pos].is_none() {
+                    if passable(&self.data[next.pos]) {
+                        distances[next.pos] = distance;
+                        owners[next.pos] = owner;
+                        queue.push_back(next);
+                    }
+                } else if distances[next.pos] == distance && owners[next.pos] != owner {
+                    owners[next.pos] = None;
+                }
+            }
+        }
+        Grid::from_valid(self.width, owners)
+    }
+
+    /// Whether no cell strictly between `a` and `b` on the Bresenham line `blocks` sight.
+    /// The endpoints themselves never block. False, if either position is outside the grid
+    ///
+    /// Only cells on the line are checked: a diagonal step squeezing between two blocking
+    /// cells (touching only at their corners) does not block sight
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let grid = Grid::from(vec![
+    ///     vec!['.', '.', '.', '.'],
+    ///     vec!['.', '#', '.', '.'],
+    ///     vec!['.', '.', '.', '#']]);
+    /// let wall = |c: &char| *c == '#';
+    /// let pos = |row, col| grid.pos_at(row, col).unwrap();
+    /// assert!(grid.line_of_sight(&pos(0, 0), &pos(0, 3), wall));
+    /// assert!(!grid.line_of_sight(&pos(0, 0), &pos(2, 2), wall));
+    /// assert!(!grid.line_of_sight(&pos(1, 0), &pos(1, 3), wall));
+    /// // Endpoints don't block
+    /// assert!(grid.line_of_sight(&pos(1, 1), &pos(2, 3), wall));
+    /// assert!(grid.line_of_sight(&pos(0, 0), &pos(0, 0), wall));
+    ///
+    /// // Squeezing diagonally between two walls
+    /// let squeeze = Grid::from(vec![
+    ///     vec!['.', '#'],
+    ///     vec!['#', '.']]);
+    /// assert!(squeeze.line_of_sight(&GridPos::new(0), &GridPos::new(3), wall));
+    /// ```
+    #[must_use]
+    pub fn line_of_sight(&self, a: &GridPos, b: &GridPos, blocks: impl Fn(&T) -> bool) -> bool {
+        let Some(line) = self.line(a, b) else {
+            return false;
+        };
+        let inner = &line[1..line.len().saturating_sub(1).max(1)];
+        !inner.iter().any(|pos| blocks(&self.data[pos.pos]))
+    }
+
+    /// The cells of the Bresenham line from `a` to `b` (both included)
+    /// (or None, if either position is outside the grid)
+    pub(super) fn line(&self, a: &GridPos, b: &GridPos) -> Option<Vec<GridPos>> {
+        let (row_a, col_a) = self.row_col(a)?;
+        let (row_b, col_b) = self.row_col(b)?;
+        let (mut row, mut col) = (row_a as isize, col_a as isize);
+        let (row_b, col_b) = (row_b as isize, col_b as isize);
+
+        let d_col = (col_b - col).abs();
+        let d_row = -(row_b - row).abs();
+        let step_col = if col < col_b { 1 } else { -1 };
+        let step_row = if row < row_b { 1 } else { -1 };
+        let mut error = d_col + d_row;
+
+        let mut line = Vec::new();
+        loop {
+            line.push(GridPos::new(row as usize * self.width + col as usize));
+            if row == row_b && col == col_b {
+                return Some(line);
+            }
+            let doubled = 2 * error;
+            if doubled >= d_row {
+                error += d_row;
+                col += step_col;
+            }
+            if doubled <= d_col {
+                error += d_col;
+                row += step_row;
+            }
+        }
+    }
+}
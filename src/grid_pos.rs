@@ -1,5 +1,42 @@
+use std::fmt::{Debug, Display};
+
+/// A cell of a grid, as the row-major index into the values of the grid.
+/// Positions are ordered by that index, so sorting them gives the row-major order
+///
+/// # Example
+///
+/// ```
+/// # use grid::*;
+/// let grid = Grid::new(3, vec!['a', 'b', 'c', 'd', 'e', 'f']);
+/// let pos = grid.pos_at(1, 1).unwrap();
+/// assert_eq!(pos.index(), 4);
+/// assert_eq!(usize::from(pos), 4);
+/// assert_eq!(GridPos::from(4), pos);
+///
+/// let mut visited = vec![false; grid.size()];
+/// visited[pos.index()] = true;
+///
+/// assert_eq!(pos.to_string(), "4");
+/// assert_eq!(format!("{pos:?}"), "GridPos(4)");
+/// ```
+///
+/// ```
+/// # use grid::*;
+/// use std::collections::HashSet;
+///
+/// let grid = Grid::new(3, vec![0; 9]);
+/// let center = grid.pos_at(1, 1).unwrap();
+/// let mut visited = HashSet::new();
+/// assert!(visited.insert(center));
+/// assert!(!visited.insert(grid.pos_at(1, 1).unwrap()));
+///
+/// let mut positions = grid.get_neighbors_flat(&center);
+/// positions.sort();
+/// assert_eq!(positions, [(0, 1), (1, 0), (1, 2), (2, 1)].map(|rc| grid.pos_of(rc).unwrap()));
+/// ```
 #[must_use]
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GridPos {
     pub(super) pos: usize,
 }
@@ -8,4 +45,50 @@ impl GridPos {
     pub fn new(pos: usize) -> Self {
         Self { pos }
     }
+
+    /// The row-major index of the position
+    #[must_use]
+    pub fn index(&self) -> usize {
+        self.pos
+    }
+
+    /// Every position from the smaller to the larger of the two indices, both included.
+    /// On a grid these wrap around from the end of one row to the start of the next
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let expected = [2, 3, 4].map(GridPos::new);
+    /// assert!(GridPos::new(2).range_to(&GridPos::new(4)).eq(expected));
+    /// assert!(GridPos::new(4).range_to(&GridPos::new(2)).eq(expected));
+    /// assert_eq!(GridPos::new(3).range_to(&GridPos::new(3)).count(), 1);
+    /// ```
+    pub fn range_to(&self, other: &GridPos) -> impl Iterator<Item = GridPos> {
+        (self.pos.min(other.pos)..=self.pos.max(other.pos)).map(GridPos::new)
+    }
+}
+
+impl From<usize> for GridPos {
+    fn from(pos: usize) -> Self {
+        GridPos::new(pos)
+    }
+}
+
+impl From<GridPos> for usize {
+    fn from(pos: GridPos) -> Self {
+        pos.pos
+    }
+}
+
+impl Debug for GridPos {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("GridPos").field(&self.pos).finish()
+    }
+}
+
+impl Display for GridPos {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.pos)
+    }
 }
@@ -0,0 +1,773 @@
+use super::grid_builder::append_row;
+use super::grid_error::GridError;
+use super::grid_grid::Grid;
+
+impl<T> Grid<T> {
+    /// Inserts a row before the row `at`, `at == height()` appends the row
+    ///
+    /// # Errors
+    ///
+    /// When the length of the row is not the width of the grid, or `at` is past the last row
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let mut grid = Grid::new(2, vec![1, 2, 3, 4]);
+    /// grid.insert_row(0, vec![7, 8]).unwrap();
+    /// grid.insert_row(3, vec![9, 9]).unwrap();
+    /// assert_eq!(grid.iter().copied().collect::<Vec<_>>(), vec![7, 8, 1, 2, 3, 4, 9, 9]);
+    /// assert_eq!(grid.size(), 8);
+    ///
+    /// assert_eq!(
+    ///     grid.insert_row(1, vec![1, 2, 3]),
+    ///     Err(GridError::RowLength { expected: 2, found: 3 }));
+    /// assert_eq!(
+    ///     grid.insert_row(5, vec![1, 2]),
+    ///     Err(GridError::RowOutOfRange { row: 5, height: 4 }));
+    /// assert_eq!(grid.size(), 8);
+    /// ```
+    pub fn insert_row(&mut self, at: usize, row: Vec<T>) -> Result<(), GridError> {
+        if row.len() != self.width {
+            return Err(GridError::RowLength {
+                expected: self.width,
+                found: row.len(),
+            });
+        }
+        let height = self.height();
+        if at > height {
+            return Err(GridError::RowOutOfRange { row: at, height });
+        }
+        let offset = at * self.width;
+        self.data.splice(offset..offset, row);
+        self.update_height();
+        Ok(())
+    }
+
+    /// Removes the row `at` and returns its values
+    ///
+    /// # Panics
+    ///
+    /// Panics, when `at` is not a row of the grid
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let mut grid = Grid::new(2, vec![1, 2, 3, 4, 5, 6]);
+    /// assert_eq!(grid.remove_row(1), vec![3, 4]);
+    /// assert_eq!(grid.iter().copied().collect::<Vec<_>>(), vec![1, 2, 5, 6]);
+    ///
+    /// let mut single = Grid::new(2, vec![1, 2]);
+    /// assert_eq!(single.remove_row(0), vec![1, 2]);
+    /// assert_eq!(single.size(), 0);
+    /// assert_eq!(single.height(), 0);
+    /// ```
+    pub fn remove_row(&mut self, at: usize) -> Vec<T> {
+        let height = self.height();
+        assert!(
+            at < height,
+            "Row {at} is out of range for a grid of height {height}"
+        );
+        let offset = at * self.width;
+        let removed = self.data.drain(offset..offset + self.width).collect();
+        self.update_height();
+        removed
+    }
+
+    /// Inserts a column before the column `at`, `at == width()` appends the column
+    ///
+    /// This changes the width, every `GridPos` obtained before no longer refers to the same cell
+    ///
+    /// # Errors
+    ///
+    /// When the length of the column is not the height of the grid, or `at` is past the last column
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// /*
+    ///     1,2,
+    ///     3,4,
+    ///     5,6,
+    ///  */
+    /// let mut grid = Grid::new(2, vec![1, 2, 3, 4, 5, 6]);
+    /// grid.insert_col(1, vec![7, 8, 9]).unwrap();
+    /// assert_eq!(grid.width(), 3);
+    /// assert_eq!(grid.get(&grid.pos_at(0, 1).unwrap()), Some(&7));
+    /// assert_eq!(grid.get(&grid.pos_at(2, 1).unwrap()), Some(&9));
+    /// assert_eq!(grid.get(&grid.pos_at(2, 2).unwrap()), Some(&6));
+    /// grid.insert_col(3, vec![0, 0, 0]).unwrap();
+    /// assert_eq!(grid.iter().copied().collect::<Vec<_>>(), vec![1, 7, 2, 0, 3, 8, 4, 0, 5, 9, 6, 0]);
+    ///
+    /// assert_eq!(
+    ///     grid.insert_col(0, vec![1, 2]),
+    ///     Err(GridError::ColumnLength { expected: 3, found: 2 }));
+    /// assert_eq!(
+    ///     grid.insert_col(5, vec![1, 2, 3]),
+    ///     Err(GridError::ColumnOutOfRange { col: 5, width: 4 }));
+    /// ```
+    pub fn insert_col(&mut self, at: usize, col: Vec<T>) -> Result<(), GridError> {
+        let height = self.height();
+        if col.len() != height {
+            return Err(GridError::ColumnLength {
+                expected: height,
+                found: col.len(),
+            });
+        }
+        if at > self.width {
+            return Err(GridError::ColumnOutOfRange {
+                col: at,
+                width: self.width,
+            });
+        }
+        let old_width = self.width;
+        let mut old = std::mem::take(&mut self.data).into_iter();
+        let mut data = Vec::with_capacity(old.len() + height);
+        for value in col {
+            data.extend(old.by_ref().take(at));
+            data.push(value);
+            data.extend(old.by_ref().take(old_width - at));
+        }
+        self.data = data;
+        self.width += 1;
+        self.update_height();
+        Ok(())
+    }
+
+    /// Removes the column `at` and returns its values.
+    /// Removing the only column leaves an empty grid of width 0
+    ///
+    /// This changes the width, every `GridPos` obtained before no longer refers to the same cell
+    ///
+    /// # Panics
+    ///
+    /// Panics, when `at` is not a column of the grid
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let mut grid = Grid::new(3, vec![1, 2, 3, 4, 5, 6]);
+    /// assert_eq!(grid.remove_col(0), vec![1, 4]);
+    /// assert_eq!(grid.width(), 2);
+    /// assert_eq!(grid.get(&grid.pos_at(1, 0).unwrap()), Some(&5));
+    /// assert_eq!(grid.remove_col(1), vec![3, 6]);
+    /// assert_eq!(grid.remove_col(0), vec![2, 5]);
+    /// assert_eq!(grid.width(), 0);
+    /// assert_eq!(grid.size(), 0);
+    /// assert_eq!(grid.pos_at(0, 0), None);
+    /// ```
+    pub fn remove_col(&mut self, at: usize) -> Vec<T> {
+        let width = self.width;
+        assert!(
+            at < width,
+            "Column {at} is out of range for a grid of width {width}"
+        );
+        let height = self.height();
+        let mut removed = Vec::with_capacity(height);
+        let mut data = Vec::with_capacity(self.size() - height);
+        for (index, value) in std::mem::take(&mut self.data).into_iter().enumerate() {
+            if index % width == at {
+                removed.push(value);
+            } else {
+                data.push(value);
+            }
+        }
+        self.data = data;
+        self.width -= 1;
+        self.update_height();
+        removed
+    }
+
+    /// Appends a row at the bottom.
+    /// On a grid of width 0 the row establishes the width, a grid with a width but
+    /// without rows keeps its width. Same rule as `extend_rows`, which this calls with the one row
+    ///
+    /// # Errors
+    ///
+    /// When the length of the row is not the width of the grid
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let mut grid: Grid<char> = Grid::new(0, vec![]);
+    /// for line in ["ab", "cd", "ef"] {
+    ///     grid.push_row(line.chars().collect()).unwrap();
+    /// }
+    /// assert_eq!(grid.width(), 2);
+    /// assert_eq!(grid.height(), 3);
+    /// assert_eq!(grid.get(&grid.pos_at(2, 0).unwrap()), Some(&'e'));
+    /// assert_eq!(
+    ///     grid.push_row(vec!['x']),
+    ///     Err(GridError::RowLength { expected: 2, found: 1 }));
+    ///
+    /// assert_eq!(grid.pop_row(), Some(vec!['e', 'f']));
+    /// assert_eq!(grid.height(), 2);
+    /// assert_eq!(Grid::<char>::new(0, vec![]).pop_row(), None);
+    ///
+    /// let mut grid: Grid<i32> = Grid::new_empty(3, 0);
+    /// assert_eq!(
+    ///     grid.push_row(vec![1, 2]),
+    ///     Err(GridError::RowLength { expected: 3, found: 2 }));
+    /// grid.push_row(vec![1, 2, 3]).unwrap();
+    /// assert_eq!((grid.width(), grid.height()), (3, 1));
+    /// ```
+    pub fn push_row(&mut self, row: Vec<T>) -> Result<(), GridError> {
+        self.extend_rows(std::iter::once(row))
+    }
+
+    /// Appends rows at the bottom, one after another.
+    /// On a grid of width 0 the first row establishes the width, also when it is empty.
+    /// A grid with a width but without rows keeps its width, like with `push_row`
+    ///
+    /// # Errors
+    ///
+    /// When the length of a row is not the width of the grid.
+    /// That row and all following rows are not appended, the rows before it are
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let mut grid: Grid<char> = Grid::new(0, vec![]);
+    /// grid.extend_rows("ab\ncd".lines().map(str::chars)).unwrap();
+    /// assert_eq!(grid.width(), 2);
+    /// assert_eq!(grid.height(), 2);
+    ///
+    /// assert_eq!(
+    ///     grid.extend_rows(["ef", "g", "hi"].map(str::chars)),
+    ///     Err(GridError::RowLength { expected: 2, found: 1 }));
+    /// assert_eq!(grid.height(), 3);
+    /// assert_eq!(grid.get(&grid.pos_at(2, 1).unwrap()), Some(&'f'));
+    ///
+    /// // A grid without rows keeps its width
+    /// let mut grid: Grid<char> = Grid::new_empty(3, 0);
+    /// assert_eq!(
+    ///     grid.extend_rows(["ab"].map(str::chars)),
+    ///     Err(GridError::RowLength { expected: 3, found: 2 }));
+    /// assert_eq!((grid.width(), grid.height()), (3, 0));
+    ///
+    /// // A leading empty row is not skipped
+    /// let mut grid: Grid<char> = Grid::new(0, vec![]);
+    /// assert_eq!(
+    ///     grid.extend_rows(["", "ab"].map(str::chars)),
+    ///     Err(GridError::RowLength { expected: 0, found: 2 }));
+    /// assert_eq!(grid.size(), 0);
+    /// ```
+    pub fn extend_rows<I, R>(&mut self, rows: I) -> Result<(), GridError>
+    where
+        I: IntoIterator<Item = R>,
+        R: IntoIterator<Item = T>,
+    {
+        let mut expected = (self.width > 0).then_some(self.width);
+        for row in rows {
+            let found = append_row(&mut self.data, expected, row).map_err(|found| {
+                GridError::RowLength {
+                    expected: self.width,
+                    found,
+                }
+            })?;
+            expected = Some(found);
+            self.width = found;
+            self.update_height();
+        }
+        Ok(())
+    }
+
+    /// Appends a column on the right.
+    /// On an empty grid (`size() == 0`) the column establishes the height and the width becomes 1
+    ///
+    /// # Errors
+    ///
+    /// When the length of the column is not the height of the grid
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let mut grid: Grid<i32> = Grid::new(0, vec![]);
+    /// grid.push_col(vec![1, 3]).unwrap();
+    /// grid.push_col(vec![2, 4]).unwrap();
+    /// assert_eq!(grid.width(), 2);
+    /// assert_eq!(grid.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+    /// assert_eq!(
+    ///     grid.push_col(vec![5]),
+    ///     Err(GridError::ColumnLength { expected: 2, found: 1 }));
+    ///
+    /// assert_eq!(grid.pop_col(), Some(vec![2, 4]));
+    /// assert_eq!(grid.pop_col(), Some(vec![1, 3]));
+    /// assert_eq!(grid.width(), 0);
+    /// assert_eq!(grid.pop_col(), None);
+    /// ```
+    pub fn push_col(&mut self, col: Vec<T>) -> Result<(), GridError> {
+        if self.size() == 0 {
+            self.width = 1;
+            self.data = col;
+            self.update_height();
+            return Ok(());
+        }
+        self.insert_col(self.width, col)
+    }
+
+    /// Removes the bottom row and returns its values (or None, if the grid has no rows)
+    pub fn pop_row(&mut self) -> Option<Vec<T>> {
+        let height = self.height();
+        if height == 0 {
+            None
+        } else {
+            Some(self.remove_row(height - 1))
+        }
+    }
+
+    /// Removes the rightmost column and returns its values (or None, if the grid has no columns)
+    pub fn pop_col(&mut self) -> Option<Vec<T>> {
+        if self.width == 0 {
+            None
+        } else {
+            Some(self.remove_col(self.width - 1))
+        }
+    }
+
+    /// Resizes the grid to `new_width` x `new_height`.
+    /// Values keep their `(row, col)`, new cells are set to `fill`,
+    /// rows and columns past the new size are dropped
+    ///
+    /// # Panics
+    ///
+    /// When `new_width * new_height` overflows `usize`, the grid is unchanged then
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// /*
+    ///     1,2,3,
+    ///     4,5,6,
+    ///  */
+    /// let mut grid = Grid::new(3, vec![1, 2, 3, 4, 5, 6]);
+    /// grid.resize(4, 3, 0);
+    /// assert_eq!(grid.width(), 4);
+    /// assert_eq!(grid.height(), 3);
+    /// assert_eq!(grid.iter().copied().collect::<Vec<_>>(), vec![
+    ///     1, 2, 3, 0,
+    ///     4, 5, 6, 0,
+    ///     0, 0, 0, 0]);
+    /// assert_eq!(grid.get(&grid.pos_at(1, 2).unwrap()), Some(&6));
+    ///
+    /// grid.resize(2, 2, 0);
+    /// assert_eq!(grid.iter().copied().collect::<Vec<_>>(), vec![1, 2, 4, 5]);
+    /// assert_eq!(grid.get(&grid.pos_at(1, 1).unwrap()), Some(&5));
+    /// ```
+    pub fn resize(&mut self, new_width: usize, new_height: usize, fill: T)
+    where
+        T: Clone,
+    {
+        let old_width = self.width;
+        let kept_rows = self.height().min(new_height);
+        let kept_cols = old_width.min(new_width);
+
+        let size = Self::cell_count(new_width, new_height);
+        let mut old = std::mem::take(&mut self.data).into_iter();
+        let mut data = Vec::with_capacity(size);
+        for _ in 0..kept_rows {
+            let row = old.by_ref().take(old_width);
+            data.extend(
+                row.enumerate()
+                    .filter(|(col, _)| *col < kept_cols)
+                    .map(|(_, v)| v),
+            );
+            data.resize(data.len() + new_width - kept_cols, fill.clone());
+        }
+        data.resize(size, fill);
+
+        self.data = data;
+        self.width = new_width;
+        self.update_height();
+    }
+
+    /// Surrounds the grid with `border` rows/columns of `fill` on every side
+    ///
+    /// # Panics
+    ///
+    /// When the number of cells of the padded grid overflows `usize`, the grid is unchanged then
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let mut grid = Grid::new(2, vec![1, 2, 3, 4]);
+    /// grid.pad(1, 0);
+    /// assert_eq!(grid.width(), 4);
+    /// assert_eq!(grid.iter().copied().collect::<Vec<_>>(), vec![
+    ///     0, 0, 0, 0,
+    ///     0, 1, 2, 0,
+    ///     0, 3, 4, 0,
+    ///     0, 0, 0, 0]);
+    /// assert_eq!(grid.get(&grid.pos_at(2, 2).unwrap()), Some(&4));
+    /// ```
+    pub fn pad(&mut self, border: usize, fill: T)
+    where
+        T: Clone,
+    {
+        let new_width = border.saturating_mul(2).saturating_add(self.width);
+        let new_height = border.saturating_mul(2).saturating_add(self.height);
+        let size = Self::cell_count(new_width, new_height);
+        let old_width = self.width;
+
+        let mut old = std::mem::take(&mut self.data).into_iter();
+        let mut data = Vec::with_capacity(size);
+        data.resize(border * new_width, fill.clone());
+        for _ in 0..new_height - 2 * border {
+            data.resize(data.len() + border, fill.clone());
+            data.extend(old.by_ref().take(old_width));
+            data.resize(data.len() + border, fill.clone());
+        }
+        data.resize(size, fill);
+
+        self.data = data;
+        self.width = new_width;
+        self.update_height();
+    }
+
+    /// Creates a grid where every cell is replicated into a block of `factor` x `factor` cells.
+    /// A factor of 0 gives an empty grid
+    ///
+    /// # Panics
+    ///
+    /// Same as `try_upscale`, with the message of its error
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let grid = Grid::new(3, vec![1, 2, 3, 4, 5, 6]);
+    /// let big = grid.upscale(2);
+    /// assert_eq!((big.width(), big.height()), (6, 4));
+    /// assert_eq!(big, Grid::new(6, vec![
+    ///     1, 1, 2, 2, 3, 3,
+    ///     1, 1, 2, 2, 3, 3,
+    ///     4, 4, 5, 5, 6, 6,
+    ///     4, 4, 5, 5, 6, 6]));
+    /// assert_eq!(big.get(&big.pos_at(3, 2).unwrap()), Some(&5));
+    /// assert_eq!(grid.upscale(1), grid);
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use grid::*;
+    /// let _ = Grid::new(2, vec![1, 2, 3, 4]).upscale(usize::MAX / 2 + 1);
+    /// ```
+    pub fn upscale(&self, factor: usize) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.try_upscale(factor)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Same as `upscale`, with an error instead of a panic when the upscaled grid is too large
+    ///
+    /// # Errors
+    ///
+    /// `GridError::TooLarge` when the upscaled width, height or number of cells overflows `usize`,
+    /// before anything is allocated. For the width and height the error holds
+    /// the dimension of this grid and the factor
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let grid = Grid::new(2, vec![1, 2, 3, 4]);
+    /// assert_eq!(grid.try_upscale(3).map(|big| big.size()), Ok(36));
+    ///
+    /// let huge = 1 << (usize::BITS / 2);
+    /// assert_eq!(
+    ///     grid.try_upscale(huge).err(),
+    ///     Some(GridError::TooLarge { width: 2 * huge, height: 2 * huge }));
+    /// assert_eq!(
+    ///     grid.try_upscale(usize::MAX).err(),
+    ///     Some(GridError::TooLarge { width: 2, height: usize::MAX }));
+    /// ```
+    pub fn try_upscale(&self, factor: usize) -> Result<Grid<T>, GridError>
+    where
+        T: Clone,
+    {
+        let width = self.width;
+        let new_width = Self::checked_cell_count(width, factor)?;
+        let new_height = Self::checked_cell_count(self.height, factor)?;
+        Self::checked_cell_count(new_width, new_height)?;
+        Ok(Grid::from_fn(new_width, new_height, |row, col| {
+            self.data[row / factor * width + col / factor].clone()
+        }))
+    }
+
+    /// Creates a grid where every block of `factor` x `factor` cells is reduced to one cell by `combine`,
+    /// which gets the values of the block in row-major order.
+    ///
+    /// When the width or height is not a multiple of `factor`, the blocks on the right and bottom edge
+    /// are smaller and `combine` gets fewer values
+    ///
+    /// # Panics
+    ///
+    /// When `factor` is 0
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let grid = Grid::new(4, vec![
+    ///     1, 2, 3, 4,
+    ///     5, 6, 7, 8]);
+    /// let max = grid.downscale(2, |block| **block.iter().max().unwrap());
+    /// assert_eq!(max, Grid::new(2, vec![6, 8]));
+    /// assert_eq!(max.upscale(2).downscale(2, |block| *block[0]), max);
+    ///
+    /// // 3x3 leaves blocks of 2x2, 1x2, 2x1 and 1x1
+    /// let grid = Grid::new(3, (1..=9).collect());
+    /// let sizes = grid.downscale(2, |block| block.len());
+    /// assert_eq!(sizes, Grid::new(2, vec![4, 2, 2, 1]));
+    /// let sums = grid.downscale(2, |block| block.iter().copied().sum());
+    /// assert_eq!(sums, Grid::new(2, vec![1 + 2 + 4 + 5, 3 + 6, 7 + 8, 9]));
+    ///
+    /// // A factor larger than the grid makes a single block of the whole grid
+    /// let grid = Grid::new(2, vec![1, 2, 3, 4]);
+    /// assert_eq!(grid.downscale(1 << 20, |block| block.len()), Grid::new(1, vec![4]));
+    /// assert_eq!(grid.downscale(usize::MAX, |block| block.len()), Grid::new(1, vec![4]));
+    /// ```
+    pub fn downscale(&self, factor: usize, combine: impl Fn(&[&T]) -> T) -> Grid<T> {
+        assert!(factor > 0, "Cannot downscale by a factor of 0");
+        let (width, height) = (self.width, self.height);
+        let mut block = Vec::with_capacity(factor.min(width) * factor.min(height));
+        Grid::from_fn(
+            width.div_ceil(factor),
+            height.div_ceil(factor),
+            |row, col| {
+                block.clear();
+                let last_row = height.min((row + 1).saturating_mul(factor));
+                let last_col = width.min((col + 1).saturating_mul(factor));
+                for r in row * factor..last_row {
+                    let start = r * width;
+                    block.extend(&self.data[start + col * factor..start + last_col]);
+                }
+                combine(&block)
+            },
+        )
+    }
+
+    /// Creates a new grid with `other` placed to the right of this grid
+    ///
+    /// # Errors
+    ///
+    /// When the heights of the grids differ
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let left = Grid::new(2, vec![1, 2, 3, 4]);
+    /// let right = Grid::new(1, vec![5, 6]);
+    /// let combined = left.concat_horizontal(&right).unwrap();
+    /// assert_eq!(combined.width(), 3);
+    /// assert_eq!(combined.get(&combined.pos_at(0, 2).unwrap()), Some(&5));
+    /// assert_eq!(combined.get(&combined.pos_at(1, 2).unwrap()), Some(&6));
+    /// assert_eq!(combined.get(&combined.pos_at(1, 0).unwrap()), Some(&3));
+    ///
+    /// assert_eq!(
+    ///     left.concat_horizontal(&Grid::new(1, vec![0])).err(),
+    ///     Some(GridError::HeightMismatch { left: 2, right: 1 }));
+    /// let owned = left.into_concat_horizontal(right).unwrap();
+    /// assert_eq!(owned.iter().collect::<Vec<_>>(), combined.iter().collect::<Vec<_>>());
+    /// ```
+    pub fn concat_horizontal(&self, other: &Grid<T>) -> Result<Grid<T>, GridError>
+    where
+        T: Clone,
+    {
+        Self::check_heights(self, other)?;
+        let data = self
+            .rows_slices()
+            .zip(other.rows_slices())
+            .flat_map(|(left, right)| left.iter().chain(right))
+            .cloned()
+            .collect();
+        Ok(Grid::new(self.width + other.width, data))
+    }
+
+    /// Consuming variant of `concat_horizontal`, moves the values instead of cloning them
+    ///
+    /// # Errors
+    ///
+    /// When the heights of the grids differ
+    pub fn into_concat_horizontal(self, other: Grid<T>) -> Result<Grid<T>, GridError> {
+        Self::check_heights(&self, &other)?;
+        let size = self.size() + other.size();
+        let (left_width, right_width) = (self.width, other.width);
+        let mut left = self.data.into_iter();
+        let mut right = other.data.into_iter();
+        let mut data = Vec::with_capacity(size);
+        while data.len() < size {
+            data.extend(left.by_ref().take(left_width));
+            data.extend(right.by_ref().take(right_width));
+        }
+        Ok(Grid::new(left_width + right_width, data))
+    }
+
+    /// Creates a new grid with `other` placed below this grid
+    ///
+    /// # Errors
+    ///
+    /// When the widths of the grids differ
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let top = Grid::new(2, vec![1, 2]);
+    /// let bottom = Grid::new(2, vec![3, 4, 5, 6]);
+    /// let combined = top.concat_vertical(&bottom).unwrap();
+    /// assert_eq!(combined.height(), 3);
+    /// assert_eq!(combined.get(&combined.pos_at(1, 0).unwrap()), Some(&3));
+    /// assert_eq!(combined.get(&combined.pos_at(2, 1).unwrap()), Some(&6));
+    ///
+    /// assert_eq!(
+    ///     top.concat_vertical(&Grid::new(3, vec![0; 3])).err(),
+    ///     Some(GridError::WidthMismatch { left: 2, right: 3 }));
+    /// let owned = top.into_concat_vertical(bottom).unwrap();
+    /// assert_eq!(owned.iter().collect::<Vec<_>>(), combined.iter().collect::<Vec<_>>());
+    /// ```
+    pub fn concat_vertical(&self, other: &Grid<T>) -> Result<Grid<T>, GridError>
+    where
+        T: Clone,
+    {
+        Self::check_widths(self, other)?;
+        let data = self.data.iter().chain(&other.data).cloned().collect();
+        Ok(Grid::new(self.width, data))
+    }
+
+    /// Consuming variant of `concat_vertical`, moves the values instead of cloning them
+    ///
+    /// # Errors
+    ///
+    /// When the widths of the grids differ
+    pub fn into_concat_vertical(mut self, other: Grid<T>) -> Result<Grid<T>, GridError> {
+        Self::check_widths(&self, &other)?;
+        self.data.extend(other.data);
+        self.update_height();
+        Ok(self)
+    }
+
+    /// Splits the grid into the rows above `row` and the rows from `row` on,
+    /// moving the values without cloning
+    ///
+    /// # Panics
+    ///
+    /// Panics, when `row` is greater than the height
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let grid = Grid::new(2, vec![1, 2, 3, 4, 5, 6]);
+    /// let (top, bottom) = grid.split_at_row(1);
+    /// assert_eq!(top.iter().copied().collect::<Vec<_>>(), vec![1, 2]);
+    /// assert_eq!(bottom.iter().copied().collect::<Vec<_>>(), vec![3, 4, 5, 6]);
+    /// assert_eq!(bottom.width(), 2);
+    ///
+    /// let (empty, all) = bottom.split_at_row(0);
+    /// assert_eq!((empty.size(), empty.width()), (0, 2));
+    /// assert_eq!(all.height(), 2);
+    /// let (all, empty) = all.split_at_row(2);
+    /// assert_eq!((all.height(), empty.height()), (2, 0));
+    /// ```
+    pub fn split_at_row(mut self, row: usize) -> (Grid<T>, Grid<T>) {
+        let height = self.height();
+        assert!(
+            row <= height,
+            "Cannot split at row {row}, the grid has height {height}"
+        );
+        let bottom = self.data.split_off(row * self.width);
+        self.update_height();
+        let width = self.width;
+        (self, Grid::new(width, bottom))
+    }
+
+    /// Splits the grid into the columns left of `col` and the columns from `col` on,
+    /// moving the values without cloning
+    ///
+    /// # Panics
+    ///
+    /// Panics, when `col` is greater than the width
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let grid = Grid::new(3, vec![1, 2, 3, 4, 5, 6]);
+    /// let (left, right) = grid.split_at_col(1);
+    /// assert_eq!(left.iter().copied().collect::<Vec<_>>(), vec![1, 4]);
+    /// assert_eq!(right.iter().copied().collect::<Vec<_>>(), vec![2, 3, 5, 6]);
+    /// assert_eq!(right.width(), 2);
+    ///
+    /// let (empty, all) = right.split_at_col(0);
+    /// assert_eq!((empty.width(), empty.size()), (0, 0));
+    /// assert_eq!(all.size(), 4);
+    /// let (all, empty) = all.split_at_col(2);
+    /// assert_eq!((all.width(), empty.width(), empty.size()), (2, 0, 0));
+    /// ```
+    pub fn split_at_col(self, col: usize) -> (Grid<T>, Grid<T>) {
+        let width = self.width;
+        assert!(
+            col <= width,
+            "Cannot split at column {col}, the grid has width {width}"
+        );
+        let height = self.height();
+        let mut left = Vec::with_capacity(col * height);
+        let mut right = Vec::with_capacity((width - col) * height);
+        for (index, value) in self.data.into_iter().enumerate() {
+            if index % width < col {
+                left.push(value);
+            } else {
+                right.push(value);
+            }
+        }
+        (Grid::new(col, left), Grid::new(width - col, right))
+    }
+
+    pub(super) fn check_shape<U>(&self, other: &Grid<U>) -> Result<(), GridError> {
+        let left = (self.width, self.height());
+        let right = (other.width, other.height());
+        if left == right && self.size() == other.size() {
+            Ok(())
+        } else {
+            Err(GridError::ShapeMismatch { left, right })
+        }
+    }
+
+    fn check_heights(left: &Grid<T>, right: &Grid<T>) -> Result<(), GridError> {
+        if left.height() == right.height() {
+            Ok(())
+        } else {
+            Err(GridError::HeightMismatch {
+                left: left.height(),
+                right: right.height(),
+            })
+        }
+    }
+
+    fn check_widths(left: &Grid<T>, right: &Grid<T>) -> Result<(), GridError> {
+        if left.width == right.width {
+            Ok(())
+        } else {
+            Err(GridError::WidthMismatch {
+                left: left.width,
+                right: right.width,
+            })
+        }
+    }
+
+    /// The rows of the grid as slices
+    pub(super) fn rows_slices(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height()).map(|row| &self.data[row * self.width..(row + 1) * self.width])
+    }
+}
@@ -0,0 +1,537 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use super::grid_error::GridError;
+use super::grid_grid::Grid;
+use super::grid_pos::GridPos;
+
+impl<T> Grid<T> {
+    /// Gets the first position (in row-major order) whose value matches `pred`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let grid = Grid::from(vec![
+    ///     vec!['.', '#', '.'],
+    ///     vec!['S', '.', '#']]);
+    /// assert_eq!(grid.find(|c| *c == 'S'), grid.pos_at(1, 0));
+    /// assert_eq!(grid.find(|c| *c == '#'), grid.pos_at(0, 1));
+    /// assert_eq!(grid.find(|c| *c == 'E'), None);
+    /// ```
+    #[must_use]
+    pub fn find(&self, pred: impl Fn(&T) -> bool) -> Option<GridPos> {
+        self.data.iter().position(pred).map(GridPos::new)
+    }
+
+    /// Gets all positions (in row-major order) whose value matches `pred`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let grid = Grid::from(vec![
+    ///     vec!['.', '#', '.'],
+    ///     vec!['S', '.', '#']]);
+    /// assert_eq!(grid.find_all(|c| *c == '#'), vec![GridPos::new(1), GridPos::new(5)]);
+    /// assert_eq!(grid.find_all(|c| *c == 'S'), vec![GridPos::new(3)]);
+    /// assert_eq!(grid.find_all(|c| *c == 'E'), vec![]);
+    /// ```
+    #[must_use]
+    pub fn find_all(&self, pred: impl Fn(&T) -> bool) -> Vec<GridPos> {
+        self.positions_where(pred).collect()
+    }
+
+    /// Lazy variant of `find_all`
+    pub fn positions_where<'a>(
+        &'a self,
+        pred: impl Fn(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = GridPos> + 'a {
+        self.data
+            .iter()
+            .enumerate()
+            .filter(move |(_, value)| pred(value))
+            .map(|(index, _)| GridPos::new(index))
+    }
+
+    /// Gets the first position (in row-major order) holding `value`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let grid = Grid::new(2, vec![3, 1, 2, 1]);
+    /// assert_eq!(grid.position_of(&1), Some(GridPos::new(1)));
+    /// assert_eq!(grid.position_of(&2), Some(GridPos::new(2)));
+    /// assert_eq!(grid.position_of(&4), None);
+    /// ```
+    #[must_use]
+    pub fn position_of(&self, value: &T) -> Option<GridPos>
+    where
+        T: PartialEq,
+    {
+        self.find(|other| other == value)
+    }
+
+    /// Whether any cell holds `value`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let grid = Grid::new(2, vec![1, 2, 2, 3]);
+    /// assert!(grid.contains(&3));
+    /// assert!(!grid.contains(&4));
+    /// assert_eq!(grid.count(&2), 2);
+    /// assert_eq!(grid.count(&4), 0);
+    /// assert_eq!(grid.count_where(|v| *v > 1), 3);
+    /// assert!(grid.any(|v| *v == 1));
+    /// assert!(grid.all(|v| *v > 0));
+    /// assert!(!grid.all(|v| *v > 1));
+    ///
+    /// let empty: Grid<i32> = Grid::new(0, vec![]);
+    /// assert!(!empty.contains(&1));
+    /// assert_eq!(empty.count_where(|_| true), 0);
+    /// assert!(!empty.any(|_| true));
+    /// assert!(empty.all(|_| false));
+    /// ```
+    #[must_use]
+    pub fn contains(&self, value: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.data.contains(value)
+    }
+
+    /// Number of cells holding `value`
+    #[must_use]
+    pub fn count(&self, value: &T) -> usize
+    where
+        T: PartialEq,
+    {
+        self.count_where(|other| other == value)
+    }
+
+    /// Number of cells whose value matches `pred`, called on every cell in row-major order
+    #[must_use]
+    pub fn count_where(&self, pred: impl Fn(&T) -> bool) -> usize {
+        self.data.iter().filter(|value| pred(value)).count()
+    }
+
+    /// Whether any value matches `pred`, called in row-major order until the first match
+    #[must_use]
+    pub fn any(&self, pred: impl Fn(&T) -> bool) -> bool {
+        self.data.iter().any(pred)
+    }
+
+    /// Whether all values match `pred`, called in row-major order until the first mismatch.
+    /// True for an empty grid
+    #[must_use]
+    pub fn all(&self, pred: impl Fn(&T) -> bool) -> bool {
+        self.data.iter().all(pred)
+    }
+
+    /// Gets the position and value of the cell with the largest key
+    /// (or None, if the grid is empty). Ties resolve to the first cell in row-major order
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let grid = Grid::new(3, vec!["a", "ccc", "b", "ddd", "", "ee"]);
+    /// assert_eq!(grid.max_by_key(|s| s.len()), Some((GridPos::new(1), &"ccc")));
+    /// assert_eq!(grid.min_by_key(|s| s.len()), Some((GridPos::new(4), &"")));
+    /// ```
+    #[must_use]
+    pub fn max_by_key<K: Ord>(&self, key: impl Fn(&T) -> K) -> Option<(GridPos, &T)> {
+        self.extremum_by_key(key, |new, best| new > best)
+    }
+
+    /// Gets the position and value of the cell with the smallest key
+    /// (or None, if the grid is empty). Ties resolve to the first cell in row-major order
+    #[must_use]
+    pub fn min_by_key<K: Ord>(&self, key: impl Fn(&T) -> K) -> Option<(GridPos, &T)> {
+        self.extremum_by_key(key, |new, best| new < best)
+    }
+
+    /// Gets the position and value of the largest cell
+    /// (or None, if the grid is empty). Ties resolve to the first cell in row-major order
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let heights = Grid::new(3, vec![5, 1, 9, 9, 1, 3]);
+    /// let (lowest, _) = heights.min().unwrap();
+    /// assert_eq!(lowest, GridPos::new(1));
+    /// assert_eq!(heights.max(), Some((GridPos::new(2), &9)));
+    ///
+    /// let empty: Grid<i32> = Grid::new(0, vec![]);
+    /// assert_eq!(empty.max(), None);
+    /// assert_eq!(empty.min(), None);
+    /// ```
+    #[must_use]
+    pub fn max(&self) -> Option<(GridPos, &T)>
+    where
+        T: Ord,
+    {
+        self.extremum_by_key(|value| value, |new, best| new > best)
+    }
+
+    /// Gets the position and value of the smallest cell
+    /// (or None, if the grid is empty). Ties resolve to the first cell in row-major order
+    #[must_use]
+    pub fn min(&self) -> Option<(GridPos, &T)>
+    where
+        T: Ord,
+    {
+        self.extremum_by_key(|value| value, |new, best| new < best)
+    }
+
+    /// Gets the top-left positions (in row-major order) of every occurrence of `pattern`,
+    /// including overlapping ones. Empty and oversized patterns have no occurrences
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let grid = Grid::from(vec![
+    ///     vec![1, 1, 1, 0],
+    ///     vec![1, 1, 1, 0],
+    ///     vec![0, 1, 1, 0]]);
+    /// let block = Grid::new(2, vec![1, 1, 1, 1]);
+    /// assert_eq!(
+    ///     grid.find_pattern(&block),
+    ///     vec![GridPos::new(0), GridPos::new(1), GridPos::new(5)]);
+    ///
+    /// let same_size = Grid::new(4, grid.iter().copied().collect());
+    /// assert_eq!(grid.find_pattern(&same_size), vec![GridPos::new(0)]);
+    /// assert_eq!(grid.find_pattern(&Grid::new(5, vec![1; 5])), vec![]);
+    /// ```
+    #[must_use]
+    pub fn find_pattern(&self, pattern: &Grid<T>) -> Vec<GridPos>
+    where
+        T: PartialEq,
+    {
+        self.find_pattern_where(pattern, |cell, expected| cell == expected)
+    }
+
+    /// Same as `find_pattern`, where `matches(cell, pattern_cell)` decides whether a cell
+    /// matches the cell of the pattern, e.g. for wildcards
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let grid = Grid::from(vec![
+    ///     vec!['a', 'x', 'c'],
+    ///     vec!['a', 'y', 'c']]);
+    /// // None matches anything
+    /// let pattern = Grid::new(3, vec![Some('a'), None, Some('c')]);
+    /// let found = grid.find_pattern_where(&pattern, |cell, expected| {
+    ///     expected.is_none_or(|expected| expected == *cell)
+    /// });
+    /// assert_eq!(found, vec![GridPos::new(0), GridPos::new(3)]);
+    /// ```
+    #[must_use]
+    pub fn find_pattern_where<P>(
+        &self,
+        pattern: &Grid<P>,
+        matches: impl Fn(&T, &P) -> bool,
+    ) -> Vec<GridPos> {
+        let (rows, cols) = (pattern.height(), pattern.width);
+        if rows == 0 || cols == 0 || rows > self.height() || cols > self.width {
+            return Vec::new();
+        }
+        let mut found = Vec::new();
+        for row in 0..=self.height() - rows {
+            for col in 0..=self.width - cols {
+                let is_match = (0..rows).all(|r| {
+                    let start = (row + r) * self.width + col;
+                    self.data[start..start + cols]
+                        .iter()
+                        .zip(&pattern.data[r * cols..(r + 1) * cols])
+                        .all(|(cell, expected)| matches(cell, expected))
+                });
+                if is_match {
+                    found.push(GridPos::new(row * self.width + col));
+                }
+            }
+        }
+        found
+    }
+
+    /// Counts how many cells hold each value
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let grid = Grid::from(vec![
+    ///     vec!['#', '.', '.'],
+    ///     vec!['o', '.', '#']]);
+    /// let counts = grid.counts();
+    /// assert_eq!(counts.len(), 3);
+    /// assert_eq!(counts[&'.'], 3);
+    /// assert_eq!(counts[&'#'], 2);
+    /// assert_eq!(counts[&'o'], 1);
+    /// ```
+    #[must_use]
+    pub fn counts(&self) -> HashMap<T, usize>
+    where
+        T: Hash + Eq + Clone,
+    {
+        self.counts_by(T::clone)
+    }
+
+    /// Counts how many cells map to each key
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let grid = Grid::new(3, vec![1.5, -2.0, 0.5, 3.0, -1.0, 0.0]);
+    /// let counts = grid.counts_by(|v: &f64| *v > 0.0);
+    /// assert_eq!(counts[&true], 3);
+    /// assert_eq!(counts[&false], 3);
+    /// ```
+    #[must_use]
+    pub fn counts_by<K: Hash + Eq>(&self, key: impl Fn(&T) -> K) -> HashMap<K, usize> {
+        let mut counts = HashMap::new();
+        for value in &self.data {
+            *counts.entry(key(value)).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    /// Whether the grid mirrors across the horizontal line between the rows `between - 1`
+    /// and `between`, comparing only as many rows as exist on the shorter side.
+    /// False for lines on the outer edge (`between == 0` or `between >= height()`)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let grid = Grid::from(vec![
+    ///     vec!['#', '.'],
+    ///     vec!['.', '.'],
+    ///     vec!['.', '#'],
+    ///     vec!['.', '#'],
+    ///     vec!['.', '.']]);
+    /// assert!(grid.is_mirror_row(3));
+    /// assert!(!grid.is_mirror_row(2));
+    /// assert!(!grid.is_mirror_row(0));
+    /// assert!(!grid.is_mirror_row(5));
+    /// assert_eq!(grid.find_mirror_rows(), vec![3]);
+    /// assert_eq!(grid.find_mirror_cols(), vec![]);
+    ///
+    /// // A fold near the edge only compares a single column
+    /// let edge = Grid::new(4, vec![1, 2, 3, 3, 4, 5, 6, 6]);
+    /// assert_eq!(edge.find_mirror_cols(), vec![3]);
+    ///
+    /// let symmetric = Grid::new(4, vec![1, 2, 2, 1, 1, 2, 2, 1]);
+    /// assert_eq!(symmetric.find_mirror_cols(), vec![2]);
+    /// assert_eq!(symmetric.find_mirror_rows(), vec![1]);
+    /// ```
+    #[must_use]
+    pub fn is_mirror_row(&self, between: usize) -> bool
+    where
+        T: PartialEq,
+    {
+        let height = self.height();
+        if between == 0 || between >= height {
+            return false;
+        }
+        let row = |r: usize| &self.data[r * self.width..(r + 1) * self.width];
+        (0..between.min(height - between))
+            .all(|offset| row(between - 1 - offset) == row(between + offset))
+    }
+
+    /// Whether the grid mirrors across the vertical line between the columns `between - 1`
+    /// and `between`, comparing only as many columns as exist on the shorter side.
+    /// False for lines on the outer edge (`between == 0` or `between >= width()`)
+    #[must_use]
+    pub fn is_mirror_col(&self, between: usize) -> bool
+    where
+        T: PartialEq,
+    {
+        let width = self.width;
+        if between == 0 || between >= width {
+            return false;
+        }
+        let overlap = between.min(width - between);
+        self.data.chunks_exact(width).all(|row| {
+            (0..overlap).all(|offset| row[between - 1 - offset] == row[between + offset])
+        })
+    }
+
+    /// All `between` for which `is_mirror_row(between)` holds, in ascending order
+    #[must_use]
+    pub fn find_mirror_rows(&self) -> Vec<usize>
+    where
+        T: PartialEq,
+    {
+        (1..self.height())
+            .filter(|between| self.is_mirror_row(*between))
+            .collect()
+    }
+
+    /// All `between` for which `is_mirror_col(between)` holds, in ascending order
+    #[must_use]
+    pub fn find_mirror_cols(&self) -> Vec<usize>
+    where
+        T: PartialEq,
+    {
+        (1..self.width)
+            .filter(|between| self.is_mirror_col(*between))
+            .collect()
+    }
+
+    /// Gets the positions (in row-major order) whose values differ between the two grids
+    ///
+    /// # Errors
+    ///
+    /// When the grids have different shapes
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let before = Grid::new(3, vec![1, 2, 3, 4, 5, 6]);
+    /// let mut after = Grid::new(3, vec![1, 2, 3, 4, 5, 6]);
+    /// assert_eq!(before.diff(&after), Ok(vec![]));
+    /// assert!(before.same_as(&after));
+    ///
+    /// after.put(&GridPos::new(1), 0);
+    /// after.put(&GridPos::new(5), 0);
+    /// assert_eq!(before.diff(&after), Ok(vec![GridPos::new(1), GridPos::new(5)]));
+    /// assert_eq!(before.diff_count(&after), Ok(2));
+    /// assert!(!before.same_as(&after));
+    ///
+    /// let other_shape = Grid::new(2, vec![1, 2, 3, 4, 5, 6]);
+    /// assert_eq!(
+    ///     before.diff(&other_shape),
+    ///     Err(GridError::ShapeMismatch { left: (3, 2), right: (2, 3) }));
+    /// assert!(!before.same_as(&other_shape));
+    /// ```
+    pub fn diff(&self, other: &Grid<T>) -> Result<Vec<GridPos>, GridError>
+    where
+        T: PartialEq,
+    {
+        Ok(self.differing(other)?.collect())
+    }
+
+    /// Number of positions whose values differ between the two grids
+    ///
+    /// # Errors
+    ///
+    /// When the grids have different shapes
+    pub fn diff_count(&self, other: &Grid<T>) -> Result<usize, GridError>
+    where
+        T: PartialEq,
+    {
+        Ok(self.differing(other)?.count())
+    }
+
+    /// Whether both grids have the same shape and values
+    #[must_use]
+    pub fn same_as(&self, other: &Grid<T>) -> bool
+    where
+        T: PartialEq,
+    {
+        self.differing(other)
+            .is_ok_and(|mut differing| differing.next().is_none())
+    }
+
+    fn differing<'a>(
+        &'a self,
+        other: &'a Grid<T>,
+    ) -> Result<impl Iterator<Item = GridPos> + 'a, GridError>
+    where
+        T: PartialEq,
+    {
+        self.check_shape(other)?;
+        Ok(self
+            .data
+            .iter()
+            .zip(&other.data)
+            .enumerate()
+            .filter(|(_, (left, right))| left != right)
+            .map(|(index, _)| GridPos::new(index)))
+    }
+
+    /// Folds every row into one value, in order of the rows
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// /*
+    ///     1, 2, 3,
+    ///     4, 5, 6,
+    ///  */
+    /// let grid = Grid::new(3, vec![1, 2, 3, 4, 5, 6]);
+    /// assert_eq!(grid.fold_rows(0, |acc, v| acc.max(*v)), vec![3, 6]);
+    /// assert_eq!(grid.fold_cols(String::new(), |acc, v| format!("{acc}{v}")), vec!["14", "25", "36"]);
+    /// assert_eq!(grid.row_sums(), vec![6, 15]);
+    /// assert_eq!(grid.col_sums(), vec![5, 7, 9]);
+    /// ```
+    pub fn fold_rows<A: Clone>(&self, init: A, f: impl Fn(A, &T) -> A) -> Vec<A> {
+        self.rows_slices()
+            .map(|row| row.iter().fold(init.clone(), &f))
+            .collect()
+    }
+
+    /// Folds every column into one value, in order of the columns
+    pub fn fold_cols<A: Clone>(&self, init: A, f: impl Fn(A, &T) -> A) -> Vec<A> {
+        (0..self.width)
+            .map(|col| self.column_values(col).fold(init.clone(), &f))
+            .collect()
+    }
+
+    /// Sum of every row, in order of the rows
+    #[must_use]
+    pub fn row_sums(&self) -> Vec<T>
+    where
+        T: for<'a> std::iter::Sum<&'a T>,
+    {
+        self.rows_slices().map(|row| row.iter().sum()).collect()
+    }
+
+    /// Sum of every column, in order of the columns
+    #[must_use]
+    pub fn col_sums(&self) -> Vec<T>
+    where
+        T: for<'a> std::iter::Sum<&'a T>,
+    {
+        (0..self.width)
+            .map(|col| self.column_values(col).sum())
+            .collect()
+    }
+
+    /// The values of the column `col`, top to bottom
+    pub(super) fn column_values(&self, col: usize) -> impl Iterator<Item = &T> {
+        self.data[..self.width * self.height()]
+            .iter()
+            .skip(col)
+            .step_by(self.width.max(1))
+    }
+
+    /// Keeps the first cell for which no later cell's key is `better`
+    fn extremum_by_key<'a, K>(
+        &'a self,
+        key: impl Fn(&'a T) -> K,
+        better: impl Fn(&K, &K) -> bool,
+    ) -> Option<(GridPos, &'a T)> {
+        let mut values = self.data.iter().enumerate();
+        let (first_index, first) = values.next()?;
+        let mut best = (first_index, first, key(first));
+        for (index, value) in values {
+            let value_key = key(value);
+            if better(&value_key, &best.2) {
+                best = (index, value, value_key);
+            }
+        }
+        Some((GridPos::new(best.0), best.1))
+    }
+}
@@ -0,0 +1,60 @@
+use serde::de::Error;
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::grid_grid::Grid;
+
+/// Serializes as a struct with the `width` and the row-major `data`
+///
+/// # Example
+///
+/// ```
+/// # use grid::*;
+/// let grid = Grid::new(3, vec![1, 2, 3, 4, 5, 6]);
+/// let json = serde_json::to_string(&grid).unwrap();
+/// assert_eq!(json, r#"{"width":3,"data":[1,2,3,4,5,6]}"#);
+///
+/// let back: Grid<i32> = serde_json::from_str(&json).unwrap();
+/// assert_eq!((back.width(), back.height()), (3, 2));
+/// assert_eq!(back.iter().collect::<Vec<_>>(), grid.iter().collect::<Vec<_>>());
+///
+/// let pos = grid.pos_at(1, 2).unwrap();
+/// let json = serde_json::to_string(&pos).unwrap();
+/// assert_eq!(serde_json::from_str::<GridPos>(&json).unwrap(), pos);
+/// ```
+impl<T: Serialize> Serialize for Grid<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("Grid", 2)?;
+        state.serialize_field("width", &self.width)?;
+        state.serialize_field("data", &self.data)?;
+        state.end()
+    }
+}
+
+/// Deserializes from a struct with the `width` and the row-major `data`,
+/// rejecting data that does not fill complete rows of the width
+///
+/// # Example
+///
+/// ```
+/// # use grid::*;
+/// let tampered = r#"{"width":4,"data":[1,2,3,4,5,6]}"#;
+/// let error = serde_json::from_str::<Grid<i32>>(tampered).err().unwrap();
+/// assert!(error.to_string().contains("6 values do not fill complete rows of width 4"));
+///
+/// let zero_width = r#"{"width":0,"data":[1]}"#;
+/// assert!(serde_json::from_str::<Grid<i32>>(zero_width).is_err());
+/// ```
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Grid<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(rename = "Grid")]
+        struct Raw<T> {
+            width: usize,
+            data: Vec<T>,
+        }
+
+        let Raw { width, data } = Raw::deserialize(deserializer)?;
+        Grid::try_new(width, data).map_err(D::Error::custom)
+    }
+}
@@ -0,0 +1,199 @@
+use std::collections::HashMap;
+
+use super::grid_direction::Direction;
+use super::grid_grid::Grid;
+
+/// A cell of an unbounded `SparseGrid`, as row and column (both may be negative)
+#[must_use]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SparsePos {
+    pub row: i64,
+    pub col: i64,
+}
+
+impl SparsePos {
+    pub fn new(row: i64, col: i64) -> Self {
+        SparsePos { row, col }
+    }
+
+    /// Gets the position `d_row` rows and `d_col` columns away (or None, if that overflows `i64`)
+    #[must_use]
+    pub fn offset(&self, d_row: i64, d_col: i64) -> Option<SparsePos> {
+        Some(SparsePos {
+            row: self.row.checked_add(d_row)?,
+            col: self.col.checked_add(d_col)?,
+        })
+    }
+
+    /// Gets the position one step in `direction` (or None, if that overflows `i64`)
+    #[must_use]
+    pub fn neighbor(&self, direction: Direction) -> Option<SparsePos> {
+        let (d_row, d_col) = direction.offset();
+        self.offset(d_row as i64, d_col as i64)
+    }
+}
+
+/// An unbounded grid that only stores the cells set to a value, every other cell has the default value
+///
+/// # Example
+///
+/// ```
+/// # use grid::*;
+/// let mut life = SparseGrid::new(false);
+/// life.put(SparsePos::new(-1_000_000, 5), true);
+/// life.put(SparsePos::new(3, -2), true);
+/// assert_eq!(life.get(&SparsePos::new(3, -2)), &true);
+/// assert_eq!(life.get(&SparsePos::new(0, 0)), &false);
+/// assert_eq!(life.len(), 2);
+///
+/// let start = SparsePos::new(0, 0);
+/// assert_eq!(
+///     life.get_neighbors(&start),
+///     [Some(SparsePos::new(-1, 0)), Some(SparsePos::new(0, 1)), Some(SparsePos::new(1, 0)), Some(SparsePos::new(0, -1))]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<SparsePos, T>,
+    default: T,
+}
+
+impl<T> SparseGrid<T> {
+    /// Creates a grid with every cell `default`
+    pub fn new(default: T) -> Self {
+        SparseGrid {
+            cells: HashMap::new(),
+            default,
+        }
+    }
+
+    /// The value of the cells that are not set
+    #[must_use]
+    pub fn default_value(&self) -> &T {
+        &self.default
+    }
+
+    /// Get a reference to the value at the position (the default value, if it is not set)
+    #[must_use]
+    pub fn get(&self, pos: &SparsePos) -> &T {
+        self.cells.get(pos).unwrap_or(&self.default)
+    }
+
+    /// Places a new value at the position, returning the value it had been set to before
+    pub fn put(&mut self, pos: SparsePos, new_value: T) -> Option<T> {
+        self.cells.insert(pos, new_value)
+    }
+
+    /// Resets the position to the default value, returning the value it had been set to
+    pub fn remove(&mut self, pos: &SparsePos) -> Option<T> {
+        self.cells.remove(pos)
+    }
+
+    /// Number of set cells
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Whether no cell is set
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Gets the neighbors Up, Right, Down, Left (None only past the range of `i64`), like `Grid::get_neighbors`
+    pub fn get_neighbors(&self, pos: &SparsePos) -> [Option<SparsePos>; 4] {
+        Direction::ORTHOGONAL.map(|direction| pos.neighbor(direction))
+    }
+
+    /// Gets all 8 neighbors clockwise from Up (None only past the range of `i64`), like `Grid::get_neighbors8`
+    pub fn get_neighbors8(&self, pos: &SparsePos) -> [Option<SparsePos>; 8] {
+        Direction::ALL.map(|direction| pos.neighbor(direction))
+    }
+
+    /// The set cells, in no particular order
+    pub fn iter_set(&self) -> impl Iterator<Item = (&SparsePos, &T)> {
+        self.cells.iter()
+    }
+
+    /// The smallest rectangle containing every set cell, as its top-left and bottom-right corner
+    /// (or None, if no cell is set)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let mut grid = SparseGrid::new(0);
+    /// assert_eq!(grid.bounds(), None);
+    /// grid.put(SparsePos::new(2, -3), 1);
+    /// grid.put(SparsePos::new(-1, 4), 2);
+    /// assert_eq!(grid.bounds(), Some((SparsePos::new(-1, -3), SparsePos::new(2, 4))));
+    /// ```
+    #[must_use]
+    pub fn bounds(&self) -> Option<(SparsePos, SparsePos)> {
+        self.cells.keys().fold(None, |bounds, pos| {
+            let (min, max) = bounds.unwrap_or((*pos, *pos));
+            Some((
+                SparsePos::new(min.row.min(pos.row), min.col.min(pos.col)),
+                SparsePos::new(max.row.max(pos.row), max.col.max(pos.col)),
+            ))
+        })
+    }
+
+    /// Copies the rectangle between the corners `top_left` and `bottom_right` (both included) into a grid,
+    /// the cell `top_left` becomes `pos_at(0, 0)`
+    ///
+    /// # Panics
+    ///
+    /// When the rectangle is empty (`bottom_right` above or left of `top_left`), or does not fit into memory
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let mut sparse = SparseGrid::new('.');
+    /// sparse.put(SparsePos::new(-1, -1), '#');
+    /// sparse.put(SparsePos::new(0, 1), '#');
+    ///
+    /// let (top_left, bottom_right) = sparse.bounds().unwrap();
+    /// let dense = sparse.to_dense(top_left, bottom_right);
+    /// assert_eq!(dense.to_string_with(|&c| c).replace(", ", ""), "#..\n..#");
+    ///
+    /// let back = SparseGrid::from_dense(&dense, '.', top_left);
+    /// assert_eq!(back, sparse);
+    /// ```
+    pub fn to_dense(&self, top_left: SparsePos, bottom_right: SparsePos) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let span = |from: i64, to: i64| {
+            to.checked_sub(from)
+                .and_then(|span| usize::try_from(span).ok())
+                .and_then(|span| span.checked_add(1))
+                .expect("bottom_right is below and right of top_left")
+        };
+        let height = span(top_left.row, bottom_right.row);
+        let width = span(top_left.col, bottom_right.col);
+        Grid::from_fn(width, height, |row, col| {
+            let pos = SparsePos::new(top_left.row + row as i64, top_left.col + col as i64);
+            self.get(&pos).clone()
+        })
+    }
+
+    /// Creates a sparse grid from the cells of `grid` that are not `default`,
+    /// the cell `pos_at(0, 0)` becomes `origin`
+    pub fn from_dense(grid: &Grid<T>, default: T, origin: SparsePos) -> Self
+    where
+        T: Clone + PartialEq,
+    {
+        let mut sparse = SparseGrid::new(default);
+        for (index, value) in grid.iter().enumerate() {
+            if *value != sparse.default {
+                let (row, col) = (index / grid.width(), index % grid.width());
+                let pos = SparsePos::new(origin.row + row as i64, origin.col + col as i64);
+                sparse.put(pos, value.clone());
+            }
+        }
+        sparse
+    }
+}
@@ -0,0 +1,1056 @@
+use super::grid_error::GridError;
+use super::grid_grid::Grid;
+use super::grid_pos::GridPos;
+use super::grid_view::OrientedView;
+
+impl<T> Grid<T> {
+    /// Creates a new grid of the same shape by calling `f` on every value
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let grid = Grid::from(vec![
+    ///     vec!['1', '2', '3'],
+    ///     vec!['4', '5', '6']]);
+    /// let heights: Grid<u32> = grid.map(|c| c.to_digit(10).unwrap());
+    /// assert_eq!(heights.width(), grid.width());
+    /// assert_eq!(heights.size(), grid.size());
+    /// assert_eq!(heights.get(&heights.pos_at(1, 2).unwrap()), Some(&6));
+    /// ```
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid::from_valid(self.width, self.data.iter().map(f).collect())
+    }
+
+    /// Consumes the grid and creates a new grid of the same shape by calling `f` on every value
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let grid = Grid::new(3, vec![String::from("a"), String::from("bb"), String::from("ccc"),
+    ///     String::from("dddd"), String::from(""), String::from("f")]);
+    /// let lengths = grid.map_into(|s| s.len());
+    /// assert_eq!(lengths.width(), 3);
+    /// assert_eq!(lengths.size(), 6);
+    /// assert_eq!(lengths.get(&lengths.pos_at(1, 0).unwrap()), Some(&4));
+    /// ```
+    pub fn map_into<U>(self, f: impl FnMut(T) -> U) -> Grid<U> {
+        Grid::from_valid(self.width, self.data.into_iter().map(f).collect())
+    }
+
+    /// Creates a new grid of the same shape by calling `f` on every position and value
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let grid = Grid::new(2, vec![10, 10, 10, 10, 10, 10]);
+    /// let mapped = grid.map_indexed(|pos, value| value + grid.row_of(pos).unwrap());
+    /// assert_eq!(mapped.width(), 2);
+    /// assert_eq!(mapped.size(), 6);
+    /// assert_eq!(mapped.iter().copied().collect::<Vec<_>>(), vec![10, 10, 11, 11, 12, 12]);
+    /// ```
+    pub fn map_indexed<U>(&self, f: impl Fn(&GridPos, &T) -> U) -> Grid<U> {
+        let data = self
+            .data
+            .iter()
+            .enumerate()
+            .map(|(index, value)| f(&GridPos::new(index), value))
+            .collect();
+        Grid::from_valid(self.width, data)
+    }
+
+    /// Combines the values of two grids of the same shape through `f`
+    ///
+    /// # Errors
+    ///
+    /// When the grids have different shapes (`GridError::ShapeMismatch`, as `(width, height)`)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let terrain = Grid::new(3, vec!['.', '#', '.', '.', '.', '~']);
+    /// let overlay = Grid::new(3, vec![1, 2, 3, 4, 5, 6]);
+    /// let costs = terrain.zip_with(&overlay, |&tile, &cost| if tile == '.' { cost } else { 100 });
+    /// assert_eq!(costs, Ok(Grid::new(3, vec![1, 100, 3, 4, 5, 100])));
+    ///
+    /// let transposed = Grid::new(2, vec![1, 2, 3, 4, 5, 6]);
+    /// assert_eq!(
+    ///     terrain.zip_with(&transposed, |_, _| 0),
+    ///     Err(GridError::ShapeMismatch { left: (3, 2), right: (2, 3) }));
+    /// ```
+    pub fn zip_with<U, V>(
+        &self,
+        other: &Grid<U>,
+        f: impl Fn(&T, &U) -> V,
+    ) -> Result<Grid<V>, GridError> {
+        self.check_shape(other)?;
+        let data = self
+            .data
+            .iter()
+            .zip(&other.data)
+            .map(|(left, right)| f(left, right))
+            .collect();
+        Ok(Grid::from_valid(self.width, data))
+    }
+
+    /// Pairs up the values of two grids of the same shape
+    ///
+    /// # Errors
+    ///
+    /// When the grids have different shapes (`GridError::ShapeMismatch`, as `(width, height)`)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let names = Grid::new(2, vec!["a", "b", "c", "d", "e", "f"]);
+    /// let values = Grid::new(2, vec![1, 2, 3, 4, 5, 6]);
+    /// let pairs = names.zip(values).unwrap();
+    /// assert_eq!(pairs.get(&pairs.pos_at(2, 1).unwrap()), Some(&("f", 6)));
+    ///
+    /// let wide = Grid::new(3, vec![1, 2, 3, 4, 5, 6]);
+    /// assert!(pairs.zip(wide).is_err());
+    /// ```
+    pub fn zip<U>(self, other: Grid<U>) -> Result<Grid<(T, U)>, GridError> {
+        self.check_shape(&other)?;
+        let width = self.width;
+        Ok(Grid::from_valid(
+            width,
+            self.data.into_iter().zip(other.data).collect(),
+        ))
+    }
+
+    /// Calls `f` on every value with the value at the same position in `other`, mutating the grid in place
+    ///
+    /// # Errors
+    ///
+    /// When the grids have different shapes (`GridError::ShapeMismatch`, as `(width, height)`),
+    /// the grid is unchanged then
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let mut total = Grid::new(3, vec![0; 6]);
+    /// let day = Grid::new(3, vec![1, 2, 3, 4, 5, 6]);
+    /// total.zip_apply(&day, |sum, &value| *sum += value).unwrap();
+    /// total.zip_apply(&day, |sum, &value| *sum += value).unwrap();
+    /// assert_eq!(total, Grid::new(3, vec![2, 4, 6, 8, 10, 12]));
+    ///
+    /// let tall = Grid::new(2, vec![1; 6]);
+    /// assert_eq!(
+    ///     total.zip_apply(&tall, |sum, &value| *sum += value),
+    ///     Err(GridError::ShapeMismatch { left: (3, 2), right: (2, 3) }));
+    /// assert_eq!(total, Grid::new(3, vec![2, 4, 6, 8, 10, 12]));
+    /// ```
+    pub fn zip_apply<U>(
+        &mut self,
+        other: &Grid<U>,
+        mut f: impl FnMut(&mut T, &U),
+    ) -> Result<(), GridError> {
+        self.check_shape(other)?;
+        for (value, other_value) in self.data.iter_mut().zip(&other.data) {
+            f(value, other_value);
+        }
+        Ok(())
+    }
+
+    /// Calls `f` on every value in row-major order, mutating the grid in place
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let mut grid = Grid::new(3, vec![1, 2, 3, 4, 5, 6]);
+    /// let aged = grid.map(|age| age + 1);
+    /// grid.apply(|age| *age += 1);
+    /// assert_eq!(grid.iter().collect::<Vec<_>>(), aged.iter().collect::<Vec<_>>());
+    /// ```
+    pub fn apply(&mut self, f: impl FnMut(&mut T)) {
+        self.data.iter_mut().for_each(f);
+    }
+
+    /// Calls `f` on every position and value in row-major order, mutating the grid in place
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let mut grid = Grid::new(2, vec![0; 4]);
+    /// let mapped = grid.map_indexed(|pos, value| value + grid.col_of(pos).unwrap());
+    /// let mut visited = Vec::new();
+    /// grid.apply_indexed(|pos, value| {
+    ///     *value += visited.len() % 2;
+    ///     visited.push(*pos);
+    /// });
+    /// assert_eq!(visited, (0..4).map(GridPos::new).collect::<Vec<_>>());
+    /// assert_eq!(grid.iter().collect::<Vec<_>>(), mapped.iter().collect::<Vec<_>>());
+    /// ```
+    pub fn apply_indexed(&mut self, mut f: impl FnMut(&GridPos, &mut T)) {
+        self.data
+            .iter_mut()
+            .enumerate()
+            .for_each(|(index, value)| f(&GridPos::new(index), value));
+    }
+
+    /// Overwrites every cell with `value`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let mut grid = Grid::new(3, vec![1, 2, 3, 4, 5, 6]);
+    /// grid.fill(0);
+    /// assert_eq!(grid.iter().collect::<Vec<_>>(), vec![&0; 6]);
+    /// assert_eq!(grid.width(), 3);
+    /// assert_eq!(grid.size(), 6);
+    /// ```
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        self.data.fill(value);
+    }
+
+    /// Overwrites every cell with the result of calling `f`, in row-major order
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let mut grid = Grid::new(2, vec![0; 4]);
+    /// let mut next = 0;
+    /// grid.fill_with(|| { next += 1; next });
+    /// assert_eq!(grid.iter().collect::<Vec<_>>(), vec![&1, &2, &3, &4]);
+    /// assert_eq!(grid.width(), 2);
+    /// ```
+    pub fn fill_with(&mut self, f: impl FnMut() -> T) {
+        self.data.fill_with(f);
+    }
+
+    /// Overwrites the `rows` x `cols` rectangle starting at `top_left` with `value`.
+    /// The rectangle is clipped to the grid
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let mut grid = Grid::new(4, vec![0; 12]);
+    /// grid.fill_region(&grid.pos_at(1, 1).unwrap(), 2, 2, 1);
+    /// assert_eq!(grid.iter().copied().collect::<Vec<_>>(), vec![
+    ///     0, 0, 0, 0,
+    ///     0, 1, 1, 0,
+    ///     0, 1, 1, 0]);
+    ///
+    /// // Clipped at the right and bottom edge
+    /// grid.fill_region(&grid.pos_at(0, 2).unwrap(), 10, 10, 2);
+    /// assert_eq!(grid.iter().copied().collect::<Vec<_>>(), vec![
+    ///     0, 0, 2, 2,
+    ///     0, 1, 2, 2,
+    ///     0, 1, 2, 2]);
+    ///
+    /// // Empty rectangles and positions outside the grid change nothing
+    /// grid.fill_region(&grid.pos_at(0, 0).unwrap(), 0, 3, 9);
+    /// grid.fill_region(&GridPos::new(12), 1, 1, 9);
+    /// assert!(!grid.iter().any(|value| *value == 9));
+    /// ```
+    pub fn fill_region(&mut self, top_left: &GridPos, rows: usize, cols: usize, value: T)
+    where
+        T: Clone,
+    {
+        let Some((row, col)) = self.row_col(top_left) else {
+            return;
+        };
+        let last_row = row.saturating_add(rows).min(self.height());
+        let last_col = col.saturating_add(cols).min(self.width);
+        for r in row..last_row {
+            let start = r * self.width;
+            self.data[start + col..start + last_col].fill(value.clone());
+        }
+    }
+
+    /// Creates a new grid with rows and columns swapped, a `W`x`H` grid becomes `H`x`W`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let grid = Grid::from(vec![
+    ///     vec![1, 2, 3, 4, 5],
+    ///     vec![6, 7, 8, 9, 10]]);
+    /// let transposed = grid.transpose();
+    /// assert_eq!(transposed.width(), 2);
+    /// assert_eq!(transposed.height(), 5);
+    /// for row in 0..grid.height() {
+    ///     for col in 0..grid.width() {
+    ///         assert_eq!(
+    ///             grid.get(&grid.pos_at(row, col).unwrap()),
+    ///             transposed.get(&transposed.pos_at(col, row).unwrap()));
+    ///     }
+    /// }
+    /// assert_eq!(
+    ///     grid.into_transposed().iter().collect::<Vec<_>>(),
+    ///     transposed.iter().collect::<Vec<_>>());
+    /// ```
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let height = self.height();
+        let width = self.width;
+        self.remapped(height, |index| {
+            let (row, col) = (index / height, index % height);
+            col * width + row
+        })
+    }
+
+    /// Consuming variant of `transpose`, moves the values instead of cloning them
+    pub fn into_transposed(self) -> Grid<T> {
+        let height = self.height();
+        let width = self.width;
+        self.reorder(height, |index| {
+            let (row, col) = (index / height, index % height);
+            col * width + row
+        })
+    }
+
+    /// Moves the values into a new grid of `new_width`,
+    /// where the value at `index` is taken from `source_index(index)` of this grid.
+    /// `source_index` must be a permutation of the (complete rows of the) grid
+    pub(super) fn reorder(
+        self,
+        new_width: usize,
+        source_index: impl Fn(usize) -> usize,
+    ) -> Grid<T> {
+        let size = self.width * self.height();
+        let mut source: Vec<Option<T>> = self.data.into_iter().take(size).map(Some).collect();
+        let data = (0..size)
+            .map(|index| {
+                source[source_index(index)]
+                    .take()
+                    .expect("source_index is a permutation")
+            })
+            .collect();
+        Grid::new(new_width, data)
+    }
+
+    /// Clones the values into a new grid of `new_width`,
+    /// where the value at `index` is taken from `source_index(index)` of this grid
+    pub(super) fn remapped(
+        &self,
+        new_width: usize,
+        source_index: impl Fn(usize) -> usize,
+    ) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let size = self.width * self.height();
+        let data = (0..size)
+            .map(|index| self.data[source_index(index)].clone())
+            .collect();
+        Grid::new(new_width, data)
+    }
+
+    /// Creates a new grid rotated by 90 degrees clockwise, a `W`x`H` grid becomes `H`x`W`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let grid = Grid::from(vec![
+    ///     vec!['a', '.', 'b'],
+    ///     vec!['c', '.', 'd']]);
+    /// let rotated = grid.rotate_cw();
+    /// assert_eq!(rotated.width(), 2);
+    /// assert_eq!(rotated.to_string(), Grid::from(vec![
+    ///     vec!['c', 'a'],
+    ///     vec!['.', '.'],
+    ///     vec!['d', 'b']]).to_string());
+    /// assert_eq!(grid.rotate_ccw().to_string(), Grid::from(vec![
+    ///     vec!['b', 'd'],
+    ///     vec!['.', '.'],
+    ///     vec!['a', 'c']]).to_string());
+    /// assert_eq!(grid.rotate_180().to_string(), Grid::from(vec![
+    ///     vec!['d', '.', 'c'],
+    ///     vec!['b', '.', 'a']]).to_string());
+    /// assert_eq!(rotated.rotate_cw().to_string(), grid.rotate_180().to_string());
+    /// assert_eq!(grid.rotate_cw().rotate_ccw().to_string(), grid.to_string());
+    /// ```
+    pub fn rotate_cw(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let height = self.height();
+        let width = self.width;
+        self.remapped(height, |index| Self::rotate_cw_source(width, height, index))
+    }
+
+    /// Creates a new grid rotated by 90 degrees counter-clockwise, a `W`x`H` grid becomes `H`x`W`
+    pub fn rotate_ccw(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let height = self.height();
+        let width = self.width;
+        self.remapped(height, |index| {
+            Self::rotate_ccw_source(width, height, index)
+        })
+    }
+
+    /// Creates a new grid rotated by 180 degrees
+    pub fn rotate_180(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let size = self.width * self.height();
+        self.remapped(self.width, |index| size - 1 - index)
+    }
+
+    /// Rotates the grid by 90 degrees clockwise, a `W`x`H` grid becomes `H`x`W`
+    ///
+    /// Square grids are rotated without allocating
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let mut square = Grid::new(3, (1..=9).collect::<Vec<_>>());
+    /// let rotated = square.rotate_cw();
+    /// square.rotate_cw_in_place();
+    /// assert_eq!(square.to_string(), rotated.to_string());
+    /// square.rotate_ccw_in_place();
+    /// square.rotate_180_in_place();
+    /// assert_eq!(square.iter().copied().collect::<Vec<_>>(), (1..=9).rev().collect::<Vec<_>>());
+    ///
+    /// let mut wide = Grid::new(3, (1..=6).collect::<Vec<_>>());
+    /// let rotated = wide.rotate_ccw();
+    /// wide.rotate_ccw_in_place();
+    /// assert_eq!(wide.width(), 2);
+    /// assert_eq!(wide.to_string(), rotated.to_string());
+    /// ```
+    pub fn rotate_cw_in_place(&mut self) {
+        if self.is_square() {
+            self.transpose_square_in_place();
+            self.data
+                .chunks_exact_mut(self.width)
+                .for_each(<[T]>::reverse);
+        } else {
+            let height = self.height();
+            let width = self.width;
+            self.reorder_in_place(height, |index| Self::rotate_cw_source(width, height, index));
+        }
+    }
+
+    /// Rotates the grid by 90 degrees counter-clockwise, a `W`x`H` grid becomes `H`x`W`
+    ///
+    /// Square grids are rotated without allocating
+    pub fn rotate_ccw_in_place(&mut self) {
+        if self.is_square() {
+            self.data
+                .chunks_exact_mut(self.width)
+                .for_each(<[T]>::reverse);
+            self.transpose_square_in_place();
+        } else {
+            let height = self.height();
+            let width = self.width;
+            self.reorder_in_place(height, |index| {
+                Self::rotate_ccw_source(width, height, index)
+            });
+        }
+    }
+
+    /// Rotates the grid by 180 degrees without allocating
+    pub fn rotate_180_in_place(&mut self) {
+        let size = self.width * self.height();
+        self.data[..size].reverse();
+    }
+
+    /// Creates a new grid with every row mirrored left-right
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let odd = Grid::new(3, (1..=6).collect::<Vec<_>>());
+    /// assert_eq!(odd.flip_horizontal().iter().copied().collect::<Vec<_>>(), vec![3, 2, 1, 6, 5, 4]);
+    /// assert_eq!(odd.flip_vertical().iter().copied().collect::<Vec<_>>(), vec![4, 5, 6, 1, 2, 3]);
+    ///
+    /// let mut even = Grid::new(2, (1..=6).collect::<Vec<_>>());
+    /// even.flip_horizontal_in_place();
+    /// assert_eq!(even.iter().copied().collect::<Vec<_>>(), vec![2, 1, 4, 3, 6, 5]);
+    /// even.flip_vertical_in_place();
+    /// assert_eq!(even.iter().copied().collect::<Vec<_>>(), vec![6, 5, 4, 3, 2, 1]);
+    /// assert_eq!(even.width(), 2);
+    /// ```
+    pub fn flip_horizontal(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let width = self.width;
+        self.remapped(width, |index| {
+            let (row, col) = (index / width, index % width);
+            row * width + (width - 1 - col)
+        })
+    }
+
+    /// Creates a new grid with the order of the rows reversed
+    pub fn flip_vertical(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let width = self.width;
+        let height = self.height();
+        self.remapped(width, |index| {
+            let (row, col) = (index / width, index % width);
+            (height - 1 - row) * width + col
+        })
+    }
+
+    /// Mirrors every row left-right without allocating
+    pub fn flip_horizontal_in_place(&mut self) {
+        if self.width == 0 {
+            return;
+        }
+        self.data
+            .chunks_exact_mut(self.width)
+            .for_each(<[T]>::reverse);
+    }
+
+    /// Reverses the order of the rows without allocating
+    pub fn flip_vertical_in_place(&mut self) {
+        let width = self.width;
+        let height = self.height();
+        for row in 0..height / 2 {
+            let (upper, lower) = self.data.split_at_mut((height - 1 - row) * width);
+            upper[row * width..(row + 1) * width].swap_with_slice(&mut lower[..width]);
+        }
+    }
+
+    /// The 8 rotations and reflections of the grid: the grid rotated clockwise by 0, 1, 2 and 3
+    /// quarter turns, followed by the same 4 mirrored left-right.
+    /// On a non-square grid the odd quarter turns have width and height swapped
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let grid = Grid::new(3, (1..=6).collect::<Vec<_>>());
+    /// let [same, cw, half, ccw, mirrored, cw_mirrored, half_mirrored, ccw_mirrored] = grid.orientations();
+    /// assert_eq!(same, grid);
+    /// assert_eq!(cw, grid.rotate_cw());
+    /// assert_eq!(half, grid.rotate_180());
+    /// assert_eq!(ccw, grid.rotate_ccw());
+    /// assert_eq!(mirrored, grid.flip_horizontal());
+    /// assert_eq!(cw_mirrored, grid.rotate_cw().flip_horizontal());
+    /// assert_eq!(half_mirrored, grid.rotate_180().flip_horizontal());
+    /// assert_eq!(ccw_mirrored, grid.rotate_ccw().flip_horizontal());
+    ///
+    /// // Only 4 of them keep the shape of a non-square grid
+    /// let shapes = grid.orientations().map(|oriented| (oriented.width(), oriented.height()));
+    /// assert_eq!(shapes.iter().filter(|&&shape| shape == (3, 2)).count(), 4);
+    /// ```
+    pub fn orientations(&self) -> [Grid<T>; 8]
+    where
+        T: Clone,
+    {
+        self.orientation_views().map(|view| view.to_grid())
+    }
+
+    /// Whether `other` is one of the `orientations` of this grid, without copying either grid
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let tile = Grid::new(3, vec!['#', '.', '.', '#', '#', '.']);
+    /// assert!(tile.equivalent_to(&tile.rotate_ccw()));
+    /// assert!(tile.equivalent_to(&tile.rotate_cw().flip_vertical()));
+    /// assert!(tile.equivalent_to(&tile));
+    ///
+    /// let other = Grid::new(3, vec!['#', '#', '.', '#', '#', '.']);
+    /// assert!(!tile.equivalent_to(&other));
+    /// assert!(!tile.equivalent_to(&Grid::new(6, tile.iter().copied().collect())));
+    /// ```
+    #[must_use]
+    pub fn equivalent_to(&self, other: &Grid<T>) -> bool
+    where
+        T: PartialEq,
+    {
+        self.orientation_views().iter().any(|view| {
+            view.width() == other.width
+                && view.height() == other.height
+                && view.iter().eq(other.data.iter())
+        })
+    }
+
+    /// The smallest of the `orientations`, comparing their values in row-major order
+    /// and the narrower grid on equal values. Equivalent grids have the same canonical grid
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let tile = Grid::new(3, vec![5, 1, 2, 3, 4, 0]);
+    /// let canonical = tile.canonical();
+    /// assert_eq!(canonical, Grid::new(2, vec![0, 2, 4, 1, 3, 5]));
+    /// assert!(canonical.equivalent_to(&tile));
+    /// assert!(tile.orientations().iter().all(|oriented| oriented.canonical() == canonical));
+    ///
+    /// // Same values in a different shape
+    /// let row = Grid::new(3, vec![1, 1, 1]);
+    /// assert_eq!(row.canonical().width(), 1);
+    /// ```
+    pub fn canonical(&self) -> Grid<T>
+    where
+        T: Ord + Clone,
+    {
+        self.orientation_views()
+            .into_iter()
+            .min_by(|a, b| a.iter().cmp(b.iter()).then(a.width().cmp(&b.width())))
+            .expect("there are 8 orientations")
+            .to_grid()
+    }
+
+    /// The views of the `orientations`, in the same order
+    fn orientation_views(&self) -> [OrientedView<'_, T>; 8] {
+        let same = self.oriented_view();
+        let cw = same.transposed().flipped_h();
+        let half = same.flipped_h().flipped_v();
+        let ccw = same.transposed().flipped_v();
+        [
+            same,
+            cw,
+            half,
+            ccw,
+            same.flipped_h(),
+            cw.flipped_h(),
+            half.flipped_h(),
+            ccw.flipped_h(),
+        ]
+    }
+
+    /// Rotates the `size` x `size` square starting at `top_left` by a quarter turn in place,
+    /// leaving the cells around it untouched
+    ///
+    /// # Errors
+    ///
+    /// When `top_left` is outside the grid (`RowOutOfRange`), or the square extends past the
+    /// bottom (`RowOutOfRange`) or right edge (`ColumnOutOfRange`) of the grid; the grid is unchanged then
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let original = Grid::new(5, (0..20).collect::<Vec<_>>());
+    /// let top_left = original.pos_at(1, 1).unwrap();
+    /// let square = original.sub_grid(&top_left, 3, 3).unwrap();
+    ///
+    /// let mut grid = original.clone();
+    /// grid.rotate_region(&top_left, 3, true).unwrap();
+    /// assert_eq!(grid.sub_grid(&top_left, 3, 3).unwrap(), square.rotate_cw());
+    /// let inside = |pos: &GridPos| {
+    ///     let (row, col) = grid.row_col(pos).unwrap();
+    ///     (1..4).contains(&row) && (1..4).contains(&col)
+    /// };
+    /// let outside: Vec<_> = grid.diff(&original).unwrap().into_iter().filter(|pos| !inside(pos)).collect();
+    /// assert_eq!(outside, vec![]);
+    ///
+    /// grid.rotate_region(&top_left, 3, false).unwrap();
+    /// assert_eq!(grid, original);
+    ///
+    /// assert_eq!(
+    ///     grid.rotate_region(&grid.pos_at(2, 1).unwrap(), 3, true),
+    ///     Err(GridError::RowOutOfRange { row: 4, height: 4 }));
+    /// assert_eq!(
+    ///     grid.rotate_region(&grid.pos_at(0, 3).unwrap(), 3, true),
+    ///     Err(GridError::ColumnOutOfRange { col: 5, width: 5 }));
+    /// assert_eq!(grid, original);
+    /// ```
+    pub fn rotate_region(
+        &mut self,
+        top_left: &GridPos,
+        size: usize,
+        clockwise: bool,
+    ) -> Result<(), GridError> {
+        let (row, col) = self.region_origin(top_left, size, size)?;
+        let index = |r: usize, c: usize| (row + r) * self.width + col + c;
+        for ring in 0..size / 2 {
+            let last = size - 1 - ring;
+            for offset in ring..last {
+                let top = index(ring, offset);
+                let right = index(offset, last);
+                let bottom = index(last, size - 1 - offset);
+                let left = index(size - 1 - offset, ring);
+                let cycle = if clockwise {
+                    [right, bottom, left]
+                } else {
+                    [left, bottom, right]
+                };
+                for other in cycle {
+                    self.data.swap(top, other);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Mirrors the `rows` x `cols` rectangle starting at `top_left` left-right in place,
+    /// leaving the cells around it untouched
+    ///
+    /// # Errors
+    ///
+    /// Same as `rotate_region`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let mut grid = Grid::new(4, (0..12).collect::<Vec<_>>());
+    /// grid.flip_region_h(&grid.pos_at(1, 0).unwrap(), 2, 3).unwrap();
+    /// assert_eq!(grid, Grid::new(4, vec![
+    ///     0, 1, 2, 3,
+    ///     6, 5, 4, 7,
+    ///     10, 9, 8, 11]));
+    /// assert!(grid.flip_region_h(&grid.pos_at(1, 0).unwrap(), 3, 3).is_err());
+    /// ```
+    pub fn flip_region_h(
+        &mut self,
+        top_left: &GridPos,
+        rows: usize,
+        cols: usize,
+    ) -> Result<(), GridError> {
+        let (row, col) = self.region_origin(top_left, rows, cols)?;
+        for r in row..row + rows {
+            let start = r * self.width + col;
+            self.data[start..start + cols].reverse();
+        }
+        Ok(())
+    }
+
+    /// Mirrors the `rows` x `cols` rectangle starting at `top_left` upside down in place,
+    /// leaving the cells around it untouched
+    ///
+    /// # Errors
+    ///
+    /// Same as `rotate_region`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let mut grid = Grid::new(4, (0..12).collect::<Vec<_>>());
+    /// grid.flip_region_v(&grid.pos_at(0, 1).unwrap(), 3, 2).unwrap();
+    /// assert_eq!(grid, Grid::new(4, vec![
+    ///     0, 9, 10, 3,
+    ///     4, 5, 6, 7,
+    ///     8, 1, 2, 11]));
+    /// assert!(grid.flip_region_v(&grid.pos_at(0, 1).unwrap(), 3, 4).is_err());
+    /// ```
+    pub fn flip_region_v(
+        &mut self,
+        top_left: &GridPos,
+        rows: usize,
+        cols: usize,
+    ) -> Result<(), GridError> {
+        let (row, col) = self.region_origin(top_left, rows, cols)?;
+        let width = self.width;
+        for r in 0..rows / 2 {
+            let (upper, lower) = self.data.split_at_mut((row + rows - 1 - r) * width);
+            let start = (row + r) * width + col;
+            upper[start..start + cols].swap_with_slice(&mut lower[col..col + cols]);
+        }
+        Ok(())
+    }
+
+    /// The `(row, col)` of `top_left`, if the `rows` x `cols` rectangle starting there fits into the grid
+    fn region_origin(
+        &self,
+        top_left: &GridPos,
+        rows: usize,
+        cols: usize,
+    ) -> Result<(usize, usize), GridError> {
+        let height = self.height;
+        let (row, col) = self.row_col(top_left).ok_or(GridError::RowOutOfRange {
+            row: top_left.pos / self.width.max(1),
+            height,
+        })?;
+        if row.saturating_add(rows) > height {
+            return Err(GridError::RowOutOfRange {
+                row: row.saturating_add(rows - 1),
+                height,
+            });
+        }
+        if col.saturating_add(cols) > self.width {
+            return Err(GridError::ColumnOutOfRange {
+                col: col.saturating_add(cols - 1),
+                width: self.width,
+            });
+        }
+        Ok((row, col))
+    }
+
+    /// Copies the `rows` x `cols` rectangle starting at `top_left` into a new grid
+    /// (or None, if the rectangle does not fit into the grid)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// /*
+    ///     0, 1, 2, 3,
+    ///     4, 5, 6, 7,
+    ///     8, 9,10,11,
+    ///  */
+    /// let grid = Grid::new(4, (0..12).collect::<Vec<_>>());
+    /// let interior = grid.sub_grid(&grid.pos_at(1, 1).unwrap(), 1, 2).unwrap();
+    /// assert_eq!(interior.width(), 2);
+    /// assert_eq!(interior.iter().copied().collect::<Vec<_>>(), vec![5, 6]);
+    ///
+    /// let corner = grid.sub_grid(&grid.pos_at(1, 2).unwrap(), 2, 2).unwrap();
+    /// assert_eq!(corner.iter().copied().collect::<Vec<_>>(), vec![6, 7, 10, 11]);
+    ///
+    /// assert!(grid.sub_grid(&grid.pos_at(1, 2).unwrap(), 2, 3).is_none());
+    /// assert!(grid.sub_grid(&grid.pos_at(1, 2).unwrap(), 3, 2).is_none());
+    /// ```
+    #[must_use]
+    pub fn sub_grid(&self, top_left: &GridPos, rows: usize, cols: usize) -> Option<Grid<T>>
+    where
+        T: Clone,
+    {
+        let (row, col) = self.row_col(top_left)?;
+        if row.checked_add(rows)? > self.height() || col.checked_add(cols)? > self.width {
+            return None;
+        }
+        let mut data = Vec::with_capacity(rows * cols);
+        for r in row..row + rows {
+            let start = r * self.width + col;
+            data.extend_from_slice(&self.data[start..start + cols]);
+        }
+        Some(Grid::new(cols, data))
+    }
+
+    /// Copies `src` into this grid with its top-left cell at `at`.
+    /// Parts of `src` hanging off the right or bottom edge are clipped
+    ///
+    /// Returns the number of cells written
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let mut grid = Grid::new(3, vec![0; 9]);
+    /// let src = Grid::new(2, vec![1, 2, 3, 4]);
+    /// assert_eq!(grid.blit(&src, &grid.pos_at(0, 0).unwrap()), 4);
+    /// assert_eq!(grid.iter().copied().collect::<Vec<_>>(), vec![
+    ///     1, 2, 0,
+    ///     3, 4, 0,
+    ///     0, 0, 0]);
+    ///
+    /// // Clipped
+    /// assert_eq!(grid.blit(&src, &grid.pos_at(2, 2).unwrap()), 1);
+    /// assert_eq!(grid.get(&grid.pos_at(2, 2).unwrap()), Some(&1));
+    /// assert_eq!(grid.blit(&src, &GridPos::new(9)), 0);
+    /// ```
+    pub fn blit(&mut self, src: &Grid<T>, at: &GridPos) -> usize
+    where
+        T: Clone,
+    {
+        self.blit_where(src, at, |_| true)
+    }
+
+    /// Same as `blit`, but skips the cells of `src` not matching `mask`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let mut grid = Grid::new(3, vec!['.'; 6]);
+    /// let sprite = Grid::new(2, vec!['#', ' ', ' ', '#']);
+    /// let written = grid.blit_where(&sprite, &grid.pos_at(0, 1).unwrap(), |c| *c != ' ');
+    /// assert_eq!(written, 2);
+    /// assert_eq!(grid.iter().collect::<String>(), ".#...#");
+    /// ```
+    pub fn blit_where(&mut self, src: &Grid<T>, at: &GridPos, mask: impl Fn(&T) -> bool) -> usize
+    where
+        T: Clone,
+    {
+        let Some((row, col)) = self.row_col(at) else {
+            return 0;
+        };
+        let rows = src.height().min(self.height() - row);
+        let cols = src.width.min(self.width - col);
+        let mut written = 0;
+        for r in 0..rows {
+            for c in 0..cols {
+                let value = &src.data[r * src.width + c];
+                if mask(value) {
+                    self.data[(row + r) * self.width + col + c] = value.clone();
+                    written += 1;
+                }
+            }
+        }
+        written
+    }
+
+    /// Cyclically shifts all rows down by `by` rows (up, if negative).
+    /// Rows shifted past the bottom re-enter at the top
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let mut grid = Grid::new(2, vec![1, 2, 3, 4, 5, 6]);
+    /// grid.roll_rows(1);
+    /// assert_eq!(grid.iter().copied().collect::<Vec<_>>(), vec![5, 6, 1, 2, 3, 4]);
+    /// grid.roll_rows(-2);
+    /// assert_eq!(grid.iter().copied().collect::<Vec<_>>(), vec![3, 4, 5, 6, 1, 2]);
+    /// // Amounts reduce modulo the height
+    /// grid.roll_rows(-7);
+    /// assert_eq!(grid.iter().copied().collect::<Vec<_>>(), vec![5, 6, 1, 2, 3, 4]);
+    /// ```
+    pub fn roll_rows(&mut self, by: isize) {
+        let height = self.height();
+        if let Some(by) = Self::roll_amount(by, height) {
+            self.data[..height * self.width].rotate_right(by * self.width);
+        }
+    }
+
+    /// Cyclically shifts all columns right by `by` columns (left, if negative).
+    /// Columns shifted past the right edge re-enter on the left
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let mut grid = Grid::new(3, vec![1, 2, 3, 4, 5, 6]);
+    /// grid.roll_cols(1);
+    /// assert_eq!(grid.iter().copied().collect::<Vec<_>>(), vec![3, 1, 2, 6, 4, 5]);
+    /// // -4 is -1 modulo the width
+    /// grid.roll_cols(-4);
+    /// assert_eq!(grid.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3, 4, 5, 6]);
+    /// ```
+    pub fn roll_cols(&mut self, by: isize) {
+        if let Some(by) = Self::roll_amount(by, self.width) {
+            self.data
+                .chunks_exact_mut(self.width)
+                .for_each(|row| row.rotate_right(by));
+        }
+    }
+
+    /// Cyclically shifts the values of a single row right by `by` (left, if negative)
+    ///
+    /// # Panics
+    ///
+    /// Panics, when `row` is not a row of the grid
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let mut grid = Grid::new(3, vec![1, 2, 3, 4, 5, 6]);
+    /// grid.roll_row(1, -1);
+    /// assert_eq!(grid.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3, 5, 6, 4]);
+    /// grid.roll_row(0, 5);
+    /// assert_eq!(grid.iter().copied().collect::<Vec<_>>(), vec![2, 3, 1, 5, 6, 4]);
+    /// ```
+    pub fn roll_row(&mut self, row: usize, by: isize) {
+        let height = self.height();
+        assert!(
+            row < height,
+            "Row {row} is out of range for a grid of height {height}"
+        );
+        if let Some(by) = Self::roll_amount(by, self.width) {
+            let start = row * self.width;
+            self.data[start..start + self.width].rotate_right(by);
+        }
+    }
+
+    /// Cyclically shifts the values of a single column down by `by` (up, if negative)
+    ///
+    /// # Panics
+    ///
+    /// Panics, when `col` is not a column of the grid
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// /*
+    ///     1,2,
+    ///     3,4,
+    ///     5,6,
+    ///  */
+    /// let mut grid = Grid::new(2, vec![1, 2, 3, 4, 5, 6]);
+    /// grid.roll_col(1, 1);
+    /// assert_eq!(grid.iter().copied().collect::<Vec<_>>(), vec![1, 6, 3, 2, 5, 4]);
+    /// grid.roll_col(0, -1);
+    /// assert_eq!(grid.iter().copied().collect::<Vec<_>>(), vec![3, 6, 5, 2, 1, 4]);
+    /// grid.roll_col(0, -5);
+    /// assert_eq!(grid.iter().copied().collect::<Vec<_>>(), vec![1, 6, 3, 2, 5, 4]);
+    /// ```
+    pub fn roll_col(&mut self, col: usize, by: isize) {
+        let width = self.width;
+        assert!(
+            col < width,
+            "Column {col} is out of range for a grid of width {width}"
+        );
+        let height = self.height();
+        if let Some(by) = Self::roll_amount(by, height) {
+            // rotate_right(by) == reverse all, then reverse both parts
+            self.reverse_col(col, 0, height);
+            self.reverse_col(col, 0, by);
+            self.reverse_col(col, by, height);
+        }
+    }
+
+    /// Reverses the rows `from..to` of a single column
+    fn reverse_col(&mut self, col: usize, from: usize, to: usize) {
+        let (mut top, mut bottom) = (from, to);
+        while top + 1 < bottom {
+            bottom -= 1;
+            self.data
+                .swap(top * self.width + col, bottom * self.width + col);
+            top += 1;
+        }
+    }
+
+    /// `by` reduced modulo `len` (None, if there is nothing to shift)
+    fn roll_amount(by: isize, len: usize) -> Option<usize> {
+        let len = isize::try_from(len).ok().filter(|len| *len > 0)?;
+        let by = by.rem_euclid(len).unsigned_abs();
+        (by != 0).then_some(by)
+    }
+
+    fn is_square(&self) -> bool {
+        self.width > 0 && self.width == self.height() && self.width * self.width == self.size()
+    }
+
+    fn transpose_square_in_place(&mut self) {
+        let width = self.width;
+        for row in 0..width {
+            for col in row + 1..width {
+                self.data.swap(row * width + col, col * width + row);
+            }
+        }
+    }
+
+    fn reorder_in_place(&mut self, new_width: usize, source_index: impl Fn(usize) -> usize) {
+        let grid = std::mem::take(self);
+        *self = grid.reorder(new_width, source_index);
+    }
+
+    /// Index in a `width`x`height` grid, that ends up at `index` after a clockwise rotation
+    fn rotate_cw_source(width: usize, height: usize, index: usize) -> usize {
+        let (row, col) = (index / height, index % height);
+        (height - 1 - col) * width + row
+    }
+
+    /// Index in a `width`x`height` grid, that ends up at `index` after a counter-clockwise rotation
+    fn rotate_ccw_source(width: usize, height: usize, index: usize) -> usize {
+        let (row, col) = (index / height, index % height);
+        col * width + (width - 1 - row)
+    }
+}
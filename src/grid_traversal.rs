@@ -0,0 +1,308 @@
+use std::collections::VecDeque;
+
+use super::grid_grid::Grid;
+use super::grid_neighbors::Neighborhood;
+use super::grid_pos::GridPos;
+
+impl<T> Grid<T> {
+    /// Gets the positions of the connected region of cells equal to the value at `start`,
+    /// in breadth-first order starting with `start`
+    ///
+    /// Calls `flood_region_with(start, Neighborhood::Orthogonal)`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let grid = Grid::from(vec![
+    ///     vec![1, 1, 0],
+    ///     vec![0, 1, 0],
+    ///     vec![1, 0, 1]]);
+    /// # let pos_of = GridPos::new;
+    /// assert_eq!(grid.flood_region(&pos_of(0)), vec![pos_of(0), pos_of(1), pos_of(4)]);
+    /// assert_eq!(grid.flood_region(&pos_of(8)), vec![pos_of(8)]);
+    /// assert_eq!(grid.flood_region(&pos_of(9)), vec![]);
+    /// ```
+    #[must_use]
+    pub fn flood_region(&self, start: &GridPos) -> Vec<GridPos>
+    where
+        T: PartialEq,
+    {
+        self.flood_region_with(start, Neighborhood::Orthogonal)
+    }
+
+    /// Gets the positions of the region of cells equal to the value at `start`,
+    /// connected through the given `Neighborhood`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let grid = Grid::from(vec![
+    ///     vec![1, 0],
+    ///     vec![0, 1]]);
+    /// assert_eq!(grid.flood_region_with(&GridPos::new(0), Neighborhood::Orthogonal).len(), 1);
+    /// assert_eq!(grid.flood_region_with(&GridPos::new(0), Neighborhood::Moore).len(), 2);
+    /// ```
+    #[must_use]
+    pub fn flood_region_with(&self, start: &GridPos, neighborhood: Neighborhood) -> Vec<GridPos>
+    where
+        T: PartialEq,
+    {
+        let Some(value) = self.get(start) else {
+            return Vec::new();
+        };
+        self.region_where(start, neighborhood, |other| other == value)
+    }
+
+    /// Replaces the connected region of cells equal to the value at `start` with `new_value`.
+    /// Returns the number of changed cells
+    ///
+    /// Calls `flood_fill_with(start, Neighborhood::Orthogonal, new_value)`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let mut grid = Grid::from(vec![
+    ///     vec!['.', '.', '#'],
+    ///     vec!['#', '.', '#'],
+    ///     vec!['.', '#', '.']]);
+    /// assert_eq!(grid.flood_fill(&GridPos::new(0), 'o'), 3);
+    /// assert_eq!(grid.iter().collect::<String>(), "oo##o#.#.");
+    /// // Filling with the current value changes nothing
+    /// assert_eq!(grid.flood_fill(&GridPos::new(0), 'o'), 0);
+    /// assert_eq!(grid.flood_fill(&GridPos::new(9), 'x'), 0);
+    ///
+    /// // No recursion, large open areas are fine
+    /// let mut open: Grid<u8> = Grid::new_empty(200, 200);
+    /// assert_eq!(open.flood_fill(&GridPos::new(0), 1), 40_000);
+    /// ```
+    pub fn flood_fill(&mut self, start: &GridPos, new_value: T) -> usize
+    where
+        T: Clone + PartialEq,
+    {
+        self.flood_fill_with(start, Neighborhood::Orthogonal, new_value)
+    }
+
+    /// Replaces the region of cells equal to the value at `start`,
+    /// connected through the given `Neighborhood`, with `new_value`.
+    /// Returns the number of changed cells
+    pub fn flood_fill_with(
+        &mut self,
+        start: &GridPos,
+        neighborhood: Neighborhood,
+        new_value: T,
+    ) -> usize
+    where
+        T: Clone + PartialEq,
+    {
+        if self.get(start).is_none_or(|value| *value == new_value) {
+            return 0;
+        }
+        let region = self.flood_region_with(start, neighborhood);
+        for pos in &region {
+            self.put(pos, new_value.clone());
+        }
+        region.len()
+    }
+
+    /// Labels every cell with the index of its connected region, where neighboring cells
+    /// belong to the same region when `same_region` holds for their values.
+    /// Returns the label grid and the number of regions
+    ///
+    /// Labels are assigned in row-major order of the first cell of each region.
+    /// Calls `connected_components_with(Neighborhood::Orthogonal, same_region)`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let grid = Grid::from(vec![
+    ///     vec!['#', '.', '#'],
+    ///     vec!['.', '#', '#'],
+    ///     vec!['#', '.', '.']]);
+    /// let (labels, count) = grid.connected_components(|a, b| a == b);
+    /// assert_eq!(count, 6);
+    /// assert_eq!(labels.iter().copied().collect::<Vec<_>>(), vec![
+    ///     0, 1, 2,
+    ///     3, 2, 2,
+    ///     4, 5, 5]);
+    ///
+    /// // Diagonally touching cells are separate regions, unless using `Neighborhood::Moore`
+    /// let (_, islands) = grid.connected_components_with(Neighborhood::Moore, |a, b| a == b);
+    /// assert_eq!(islands, 2);
+    ///
+    /// let uniform = Grid::new(3, vec![7; 9]);
+    /// let (labels, count) = uniform.connected_components(|a, b| a == b);
+    /// assert_eq!(count, 1);
+    /// assert!(labels.iter().all(|label| *label == 0));
+    /// ```
+    pub fn connected_components(
+        &self,
+        same_region: impl Fn(&T, &T) -> bool,
+    ) -> (Grid<usize>, usize) {
+        self.connected_components_with(Neighborhood::Orthogonal, same_region)
+    }
+
+    /// Same as `connected_components`, with cells connected through the given `Neighborhood`
+    pub fn connected_components_with(
+        &self,
+        neighborhood: Neighborhood,
+        same_region: impl Fn(&T, &T) -> bool,
+    ) -> (Grid<usize>, usize) {
+        self.components_by(|pos| self.neighbors_in(pos, neighborhood), same_region)
+    }
+
+    /// Labels the connected regions like `connected_components`,
+    /// stepping from a cell to the positions returned by `neighbors`
+    pub(super) fn components_by<N>(
+        &self,
+        neighbors: impl Fn(&GridPos) -> N,
+        same_region: impl Fn(&T, &T) -> bool,
+    ) -> (Grid<usize>, usize)
+    where
+        N: IntoIterator<Item = GridPos>,
+    {
+        let mut labels: Vec<Option<usize>> = vec![None; self.size()];
+        let mut count = 0;
+        let mut queue = VecDeque::new();
+        for start in 0..self.size() {
+            if labels[start].is_some() {
+                continue;
+            }
+            labels[start] = Some(count);
+            queue.push_back(GridPos::new(start));
+            while let Some(current) = queue.pop_front() {
+                let value = &self.data[current.pos];
+                for next in neighbors(&current) {
+                    if labels[next.pos].is_none() && same_region(value, &self.data[next.pos]) {
+                        labels[next.pos] = Some(count);
+                        queue.push_back(next);
+                    }
+                }
+            }
+            count += 1;
+        }
+        let labels = labels.into_iter().flatten().collect();
+        (Grid::from_valid(self.width, labels), count)
+    }
+
+    /// Gets the positions (in row-major order) of the cells matching `pred` that have at least
+    /// one exposed side: a Neighbor not matching `pred`, or, if `edge_is_exposed`, the grid edge
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let grid = Grid::from(vec![
+    ///     vec!['#', '#', '#', '.'],
+    ///     vec!['#', '#', '#', '.'],
+    ///     vec!['#', '#', '#', '.'],
+    ///     vec!['.', '.', '.', '.']]);
+    /// let wall = |c: &char| *c == '#';
+    /// assert_eq!(grid.region_boundary(wall, true).len(), 8);
+    /// // Only the cells facing the '.' cells
+    /// assert_eq!(
+    ///     grid.region_boundary(wall, false),
+    ///     [(0, 2), (1, 2), (2, 0), (2, 1), (2, 2)].map(|(r, c)| grid.pos_at(r, c).unwrap()));
+    /// ```
+    #[must_use]
+    pub fn region_boundary(
+        &self,
+        pred: impl Fn(&T) -> bool,
+        edge_is_exposed: bool,
+    ) -> Vec<GridPos> {
+        (0..self.size())
+            .map(GridPos::new)
+            .filter(|pos| pred(&self.data[pos.pos]))
+            .filter(|pos| self.exposed_sides(pos, &pred, edge_is_exposed) > 0)
+            .collect()
+    }
+
+    /// Counts the exposed sides (not the cells) of all cells matching `pred`:
+    /// sides facing a Neighbor not matching `pred`, or, if `edge_is_exposed`, the grid edge
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let single = Grid::from(vec![
+    ///     vec![0, 0, 0],
+    ///     vec![0, 1, 0],
+    ///     vec![0, 0, 0]]);
+    /// assert_eq!(single.perimeter_length(|v| *v == 1, true), 4);
+    ///
+    /// let block = Grid::from(vec![
+    ///     vec![0, 0, 0, 0],
+    ///     vec![0, 1, 1, 0],
+    ///     vec![0, 1, 1, 0]]);
+    /// assert_eq!(block.perimeter_length(|v| *v == 1, true), 8);
+    /// // The block touches the bottom edge
+    /// assert_eq!(block.perimeter_length(|v| *v == 1, false), 6);
+    /// ```
+    #[must_use]
+    pub fn perimeter_length(&self, pred: impl Fn(&T) -> bool, edge_is_exposed: bool) -> usize {
+        (0..self.size())
+            .map(GridPos::new)
+            .filter(|pos| pred(&self.data[pos.pos]))
+            .map(|pos| self.exposed_sides(&pos, &pred, edge_is_exposed))
+            .sum()
+    }
+
+    fn exposed_sides(
+        &self,
+        position: &GridPos,
+        pred: impl Fn(&T) -> bool,
+        edge_is_exposed: bool,
+    ) -> usize {
+        self.get_neighbors(position)
+            .into_iter()
+            .filter(|neighbor| match neighbor {
+                Some(neighbor) => !pred(&self.data[neighbor.pos]),
+                None => edge_is_exposed,
+            })
+            .count()
+    }
+
+    /// Breadth-first search from `start` over the cells matching `pred`
+    /// (the value at `start` must match as well)
+    pub(super) fn region_where(
+        &self,
+        start: &GridPos,
+        neighborhood: Neighborhood,
+        pred: impl Fn(&T) -> bool,
+    ) -> Vec<GridPos> {
+        self.region_by(start, |pos| self.neighbors_in(pos, neighborhood), pred)
+    }
+
+    /// Breadth-first search from `start` over the cells matching `pred`,
+    /// stepping from a cell to the positions returned by `neighbors`
+    pub(super) fn region_by<N>(
+        &self,
+        start: &GridPos,
+        neighbors: impl Fn(&GridPos) -> N,
+        pred: impl Fn(&T) -> bool,
+    ) -> Vec<GridPos>
+    where
+        N: IntoIterator<Item = GridPos>,
+    {
+        if !self.get(start).is_some_and(&pred) {
+            return Vec::new();
+        }
+        let mut visited = vec![false; self.size()];
+        let mut queue = VecDeque::from([*start]);
+        visited[start.pos] = true;
+        let mut region = Vec::new();
+        while let Some(current) = queue.pop_front() {
+            region.push(current);
+            for next in neighbors(&current) {
+                if !visited[next.pos] && self.get(&next).is_some_and(&pred) {
+                    visited[next.pos] = true;
+                    queue.push_back(next);
+                }
+            }
+        }
+        region
+    }
+}
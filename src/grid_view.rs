@@ -0,0 +1,505 @@
+use super::grid_direction::Direction;
+use super::grid_grid::Grid;
+use super::grid_pos::GridPos;
+
+/// Where a view lies in its parent grid.
+/// Positions of a view are relative to the view: `GridPos` 0 is its top-left cell
+#[derive(Debug, Clone, Copy)]
+struct Window {
+    /// Parent index of the top-left cell
+    origin: usize,
+    /// Width of the parent
+    stride: usize,
+    width: usize,
+    height: usize,
+}
+
+impl Window {
+    fn new<T>(grid: &Grid<T>, top_left: &GridPos, rows: usize, cols: usize) -> Option<Window> {
+        let (row, col) = grid.row_col(top_left)?;
+        if row.checked_add(rows)? > grid.height() || col.checked_add(cols)? > grid.width() {
+            return None;
+        }
+        Some(Window {
+            origin: top_left.pos,
+            stride: grid.width(),
+            width: cols,
+            height: if cols == 0 { 0 } else { rows },
+        })
+    }
+
+    /// Range of the parent data the view covers
+    fn span(&self) -> std::ops::Range<usize> {
+        let len = match self.height {
+            0 => 0,
+            height => (height - 1) * self.stride + self.width,
+        };
+        self.origin..self.origin + len
+    }
+
+    fn size(&self) -> usize {
+        self.width * self.height
+    }
+
+    fn pos_at(&self, row: usize, col: usize) -> Option<GridPos> {
+        (row < self.height && col < self.width).then(|| GridPos::new(row * self.width + col))
+    }
+
+    fn row_col(&self, pos: &GridPos) -> Option<(usize, usize)> {
+        (pos.pos < self.size()).then(|| (pos.pos / self.width, pos.pos % self.width))
+    }
+
+    /// Index of `pos` in the `span()` of the parent data
+    fn local_index(&self, pos: &GridPos) -> Option<usize> {
+        self.row_col(pos).map(|(row, col)| row * self.stride + col)
+    }
+
+    fn parent_pos(&self, pos: &GridPos) -> Option<GridPos> {
+        self.local_index(pos)
+            .map(|index| GridPos::new(self.origin + index))
+    }
+
+    fn get_neighbors(&self, pos: &GridPos) -> [Option<GridPos>; 4] {
+        Direction::ORTHOGONAL.map(|direction| {
+            let (row, col) = self.row_col(pos)?;
+            let (d_row, d_col) = direction.offset();
+            self.pos_at(
+                row.checked_add_signed(d_row)?,
+                col.checked_add_signed(d_col)?,
+            )
+        })
+    }
+}
+
+/// A borrowed rectangle of a grid, see `Grid::view`
+///
+/// Positions are relative to the view, `parent_pos` maps them to the grid
+#[derive(Debug)]
+pub struct GridView<'a, T> {
+    data: &'a [T],
+    window: Window,
+}
+
+impl<T> Clone for GridView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for GridView<'_, T> {}
+
+/// A mutably borrowed rectangle of a grid, see `Grid::view_mut`
+///
+/// Positions are relative to the view, `parent_pos` maps them to the grid
+#[derive(Debug)]
+pub struct GridViewMut<'a, T> {
+    data: &'a mut [T],
+    window: Window,
+}
+
+impl<T> Grid<T> {
+    /// Borrows the `rows` x `cols` rectangle starting at `top_left` without copying it
+    /// (or None, if the rectangle does not fit into the grid), see `sub_grid` for the copying version
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// /*
+    ///     0, 1, 2, 3, 4,
+    ///     5, 6, 7, 8, 9,
+    ///    10,11,12,13,14,
+    ///  */
+    /// let grid = Grid::new(5, (0..15).collect::<Vec<_>>());
+    /// let view = grid.view(&grid.pos_at(1, 2).unwrap(), 2, 3).unwrap();
+    /// assert_eq!((view.width(), view.height()), (3, 2));
+    ///
+    /// let pos = view.pos_at(1, 0).unwrap();
+    /// assert_eq!(view.get(&pos), Some(&12));
+    /// assert_eq!(view.parent_pos(&pos), grid.pos_at(2, 2));
+    /// assert_eq!(view.get_neighbors(&pos), [view.pos_at(0, 0), view.pos_at(1, 1), None, None]);
+    ///
+    /// assert_eq!(view.iter().copied().collect::<Vec<_>>(), vec![7, 8, 9, 12, 13, 14]);
+    /// assert_eq!(view.rows().collect::<Vec<_>>(), vec![&[7, 8, 9][..], &[12, 13, 14][..]]);
+    /// assert_eq!(view.to_grid(), grid.sub_grid(&grid.pos_at(1, 2).unwrap(), 2, 3).unwrap());
+    ///
+    /// assert!(grid.view(&grid.pos_at(1, 3).unwrap(), 2, 3).is_none());
+    /// ```
+    #[must_use]
+    pub fn view(&self, top_left: &GridPos, rows: usize, cols: usize) -> Option<GridView<'_, T>> {
+        let window = Window::new(self, top_left, rows, cols)?;
+        Some(GridView {
+            data: &self.data[window.span()],
+            window,
+        })
+    }
+
+    /// Mutably borrows the `rows` x `cols` rectangle starting at `top_left` without copying it
+    /// (or None, if the rectangle does not fit into the grid)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let mut grid = Grid::new(4, vec![0; 12]);
+    /// let mut tile = grid.view_mut(&grid.pos_at(1, 1).unwrap(), 2, 2).unwrap();
+    /// tile.put(&tile.pos_at(0, 1).unwrap(), 5);
+    /// tile.iter_mut().for_each(|value| *value += 1);
+    /// assert_eq!(tile.as_view().iter().copied().collect::<Vec<_>>(), vec![1, 6, 1, 1]);
+    ///
+    /// assert_eq!(grid.to_rows(), vec![
+    ///     vec![0, 0, 0, 0],
+    ///     vec![0, 1, 6, 0],
+    ///     vec![0, 1, 1, 0]]);
+    /// ```
+    #[must_use]
+    pub fn view_mut(
+        &mut self,
+        top_left: &GridPos,
+        rows: usize,
+        cols: usize,
+    ) -> Option<GridViewMut<'_, T>> {
+        let window = Window::new(self, top_left, rows, cols)?;
+        Some(GridViewMut {
+            data: &mut self.data[window.span()],
+            window,
+        })
+    }
+}
+
+impl<'a, T> GridView<'a, T> {
+    #[must_use]
+    pub fn width(&self) -> usize {
+        self.window.width
+    }
+
+    #[must_use]
+    pub fn height(&self) -> usize {
+        self.window.height
+    }
+
+    #[must_use]
+    pub fn size(&self) -> usize {
+        self.window.size()
+    }
+
+    /// Gets the view-relative `GridPos` at the 0-indexed `row` and `col` of the view
+    #[must_use]
+    pub fn pos_at(&self, row: usize, col: usize) -> Option<GridPos> {
+        self.window.pos_at(row, col)
+    }
+
+    /// Gets the 0-indexed `(row, col)` in the view of a view-relative `GridPos`
+    #[must_use]
+    pub fn row_col(&self, pos: &GridPos) -> Option<(usize, usize)> {
+        self.window.row_col(pos)
+    }
+
+    /// Maps a view-relative `GridPos` to the same cell in the parent grid
+    #[must_use]
+    pub fn parent_pos(&self, pos: &GridPos) -> Option<GridPos> {
+        self.window.parent_pos(pos)
+    }
+
+    /// Get a reference to the value at the view-relative position
+    #[must_use]
+    pub fn get(&self, pos: &GridPos) -> Option<&'a T> {
+        self.window.local_index(pos).map(|index| &self.data[index])
+    }
+
+    /// Gets the neighbors inside the view, in the same order as `Grid::get_neighbors`
+    #[must_use]
+    pub fn get_neighbors(&self, pos: &GridPos) -> [Option<GridPos>; 4] {
+        self.window.get_neighbors(pos)
+    }
+
+    /// The rows of the view as slices of the parent grid
+    pub fn rows(&self) -> impl Iterator<Item = &'a [T]> {
+        let width = self.width();
+        self.data
+            .chunks(self.window.stride.max(1))
+            .map(move |row| &row[..width])
+    }
+
+    /// The values of the view in row-major order
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> {
+        self.rows().flatten()
+    }
+
+    /// Copies the view into a new grid
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_valid(self.width(), self.iter().cloned().collect())
+    }
+}
+
+impl<T> GridViewMut<'_, T> {
+    /// A read-only view of the same cells
+    #[must_use]
+    pub fn as_view(&self) -> GridView<'_, T> {
+        GridView {
+            data: self.data,
+            window: self.window,
+        }
+    }
+
+    #[must_use]
+    pub fn width(&self) -> usize {
+        self.window.width
+    }
+
+    #[must_use]
+    pub fn height(&self) -> usize {
+        self.window.height
+    }
+
+    #[must_use]
+    pub fn size(&self) -> usize {
+        self.window.size()
+    }
+
+    /// Gets the view-relative `GridPos` at the 0-indexed `row` and `col` of the view
+    #[must_use]
+    pub fn pos_at(&self, row: usize, col: usize) -> Option<GridPos> {
+        self.window.pos_at(row, col)
+    }
+
+    /// Gets the 0-indexed `(row, col)` in the view of a view-relative `GridPos`
+    #[must_use]
+    pub fn row_col(&self, pos: &GridPos) -> Option<(usize, usize)> {
+        self.window.row_col(pos)
+    }
+
+    /// Maps a view-relative `GridPos` to the same cell in the parent grid
+    #[must_use]
+    pub fn parent_pos(&self, pos: &GridPos) -> Option<GridPos> {
+        self.window.parent_pos(pos)
+    }
+
+    /// Get a reference to the value at the view-relative position
+    #[must_use]
+    pub fn get(&self, pos: &GridPos) -> Option<&T> {
+        self.window.local_index(pos).map(|index| &self.data[index])
+    }
+
+    /// Gets a mutable reference to the value at the view-relative position
+    #[must_use]
+    pub fn get_mut(&mut self, pos: &GridPos) -> Option<&mut T> {
+        self.window
+            .local_index(pos)
+            .map(|index| &mut self.data[index])
+    }
+
+    /// Places a new value at the view-relative position
+    pub fn put(&mut self, pos: &GridPos, new_value: T) {
+        if let Some(value) = self.get_mut(pos) {
+            *value = new_value;
+        }
+    }
+
+    /// Gets the neighbors inside the view, in the same order as `Grid::get_neighbors`
+    #[must_use]
+    pub fn get_neighbors(&self, pos: &GridPos) -> [Option<GridPos>; 4] {
+        self.window.get_neighbors(pos)
+    }
+
+    /// The rows of the view as mutable slices of the parent grid
+    pub fn rows_mut(&mut self) -> impl Iterator<Item = &mut [T]> {
+        let width = self.window.width;
+        self.data
+            .chunks_mut(self.window.stride.max(1))
+            .map(move |row| &mut row[..width])
+    }
+
+    /// The values of the view in row-major order
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.rows_mut().flatten()
+    }
+}
+
+/// A read-only view of a whole grid that is transposed and/or flipped, without copying.
+/// See `Grid::transposed_view`, `Grid::flipped_h_view` and `Grid::flipped_v_view`
+///
+/// Positions are relative to the view (row-major in the orientation of the view)
+#[derive(Debug)]
+pub struct OrientedView<'a, T> {
+    data: &'a [T],
+    /// Parent index of the top-left cell
+    origin: isize,
+    /// Change of the parent index one row down in the view
+    row_step: isize,
+    /// Change of the parent index one column right in the view
+    col_step: isize,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Clone for OrientedView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for OrientedView<'_, T> {}
+
+impl<T> Grid<T> {
+    /// A view with rows and columns swapped, like `transpose` without copying
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let grid = Grid::new(3, (1..=6).collect::<Vec<_>>());
+    /// let view = grid.transposed_view();
+    /// assert_eq!((view.width(), view.height()), (2, 3));
+    /// assert_eq!(view.get(&view.pos_at(2, 0).unwrap()), Some(&3));
+    /// assert_eq!(view.to_grid(), grid.transpose());
+    ///
+    /// let columns: Vec<Vec<i32>> = view.rows().map(|col| col.copied().collect()).collect();
+    /// assert_eq!(columns, vec![vec![1, 4], vec![2, 5], vec![3, 6]]);
+    ///
+    /// // views compose
+    /// assert_eq!(grid.transposed_view().flipped_h().to_grid(), grid.rotate_cw());
+    /// assert_eq!(grid.flipped_v_view().transposed().to_grid(), grid.rotate_cw());
+    /// assert_eq!(view.transposed().to_grid(), grid);
+    /// ```
+    #[must_use]
+    pub fn transposed_view(&self) -> OrientedView<'_, T> {
+        self.oriented_view().transposed()
+    }
+
+    /// A view with the order of the columns reversed, like `flip_horizontal` without copying
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let grid = Grid::new(5, (0..10).collect::<Vec<_>>());
+    /// assert_eq!(grid.flipped_h_view().to_grid(), grid.flip_horizontal());
+    /// assert_eq!(grid.flipped_v_view().to_grid(), grid.flip_vertical());
+    /// assert_eq!(grid.flipped_h_view().flipped_v().to_grid(), grid.rotate_180());
+    /// assert_eq!(grid.flipped_h_view().iter().take(5).copied().collect::<Vec<_>>(), vec![4, 3, 2, 1, 0]);
+    /// ```
+    #[must_use]
+    pub fn flipped_h_view(&self) -> OrientedView<'_, T> {
+        self.oriented_view().flipped_h()
+    }
+
+    /// A view with the order of the rows reversed, like `flip_vertical` without copying
+    #[must_use]
+    pub fn flipped_v_view(&self) -> OrientedView<'_, T> {
+        self.oriented_view().flipped_v()
+    }
+
+    /// The grid as it is
+    pub(super) fn oriented_view(&self) -> OrientedView<'_, T> {
+        OrientedView {
+            data: &self.data,
+            origin: 0,
+            row_step: self.width as isize,
+            col_step: 1,
+            width: self.width,
+            height: self.height(),
+        }
+    }
+}
+
+impl<'a, T> OrientedView<'a, T> {
+    #[must_use]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    #[must_use]
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    #[must_use]
+    pub fn size(&self) -> usize {
+        self.width * self.height
+    }
+
+    /// This view with rows and columns swapped
+    #[must_use]
+    pub fn transposed(self) -> Self {
+        OrientedView {
+            row_step: self.col_step,
+            col_step: self.row_step,
+            width: self.height,
+            height: self.width,
+            ..self
+        }
+    }
+
+    /// This view with the order of the columns reversed
+    #[must_use]
+    pub fn flipped_h(self) -> Self {
+        OrientedView {
+            origin: self.origin + self.width.saturating_sub(1) as isize * self.col_step,
+            col_step: -self.col_step,
+            ..self
+        }
+    }
+
+    /// This view with the order of the rows reversed
+    #[must_use]
+    pub fn flipped_v(self) -> Self {
+        OrientedView {
+            origin: self.origin + self.height.saturating_sub(1) as isize * self.row_step,
+            row_step: -self.row_step,
+            ..self
+        }
+    }
+
+    /// Gets the view-relative `GridPos` at the 0-indexed `row` and `col` of the view
+    #[must_use]
+    pub fn pos_at(&self, row: usize, col: usize) -> Option<GridPos> {
+        (row < self.height && col < self.width).then(|| GridPos::new(row * self.width + col))
+    }
+
+    /// Gets the 0-indexed `(row, col)` in the view of a view-relative `GridPos`
+    #[must_use]
+    pub fn row_col(&self, pos: &GridPos) -> Option<(usize, usize)> {
+        (pos.pos < self.size()).then(|| (pos.pos / self.width, pos.pos % self.width))
+    }
+
+    /// Maps a view-relative `GridPos` to the same cell in the parent grid
+    #[must_use]
+    pub fn parent_pos(&self, pos: &GridPos) -> Option<GridPos> {
+        self.row_col(pos)
+            .map(|(row, col)| GridPos::new(self.parent_index(row, col)))
+    }
+
+    /// Get a reference to the value at the view-relative position
+    #[must_use]
+    pub fn get(&self, pos: &GridPos) -> Option<&'a T> {
+        self.parent_pos(pos).map(|parent| &self.data[parent.pos])
+    }
+
+    /// The rows of the view, each as an iterator over its values
+    pub fn rows(&self) -> impl Iterator<Item = impl Iterator<Item = &'a T>> {
+        let view = *self;
+        (0..self.height)
+            .map(move |row| (0..view.width).map(move |col| &view.data[view.parent_index(row, col)]))
+    }
+
+    /// The values of the view in row-major order
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> {
+        self.rows().flatten()
+    }
+
+    /// Copies the view into a new grid
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_valid(self.width, self.iter().cloned().collect())
+    }
+
+    fn parent_index(&self, row: usize, col: usize) -> usize {
+        (self.origin + row as isize * self.row_step + col as isize * self.col_step) as usize
+    }
+}
@@ -0,0 +1,308 @@
+use std::ops::{Deref, DerefMut};
+
+use super::grid_direction::Direction;
+use super::grid_grid::Grid;
+use super::grid_neighbors::Neighborhood;
+use super::grid_pos::GridPos;
+
+/// A grid on a torus: leaving one edge enters at the opposite edge, in both axes
+///
+/// Everything else (shape, `iter`, `put`, ...) is the inner grid through `Deref`.
+/// The flood and connected component methods are wrapping versions here, but the other
+/// searches reached through `Deref` (`shortest_path`, `astar`, `distance_map`,
+/// `reachable_within`, `region_boundary`, `step`, ...) stop at the edges of the inner grid
+///
+/// # Example
+///
+/// ```
+/// # use grid::*;
+/// /*
+///     0, 1, 2,
+///     3, 4, 5,
+///  */
+/// let grid = Grid::new(3, (0..6).collect::<Vec<_>>()).into_wrapping();
+/// assert_eq!(grid.get(-1, -1), Some(&5));
+/// assert_eq!(grid.get(2, 3), Some(&0));
+/// assert_eq!(grid.get(-7, 4), Some(&4));
+/// assert_eq!(grid.width(), 3);
+///
+/// let corner = grid.pos_at(0, 0).unwrap();
+/// assert_eq!(grid.offset(&corner, -1, -1), GridPos::new(5));
+/// assert_eq!(grid.get_neighbors(&corner), [3, 1, 3, 2].map(GridPos::new));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct WrappingGrid<T> {
+    grid: Grid<T>,
+}
+
+impl<T> Grid<T> {
+    /// Wraps the grid into a `WrappingGrid`
+    pub fn into_wrapping(self) -> WrappingGrid<T> {
+        WrappingGrid::new(self)
+    }
+}
+
+impl<T> WrappingGrid<T> {
+    pub fn new(grid: Grid<T>) -> Self {
+        WrappingGrid { grid }
+    }
+
+    /// Unwraps the inner grid
+    pub fn into_inner(self) -> Grid<T> {
+        self.grid
+    }
+
+    /// Gets the `GridPos` at `row` and `col`, wrapped into the grid (None only for an empty grid)
+    #[must_use]
+    pub fn pos_at(&self, row: isize, col: isize) -> Option<GridPos> {
+        let (width, height) = (self.grid.width() as isize, self.grid.height() as isize);
+        if width == 0 || height == 0 {
+            return None;
+        }
+        let (row, col) = (row.rem_euclid(height), col.rem_euclid(width));
+        Some(GridPos::new((row * width + col) as usize))
+    }
+
+    /// Get a reference to the value at `row` and `col`, wrapped into the grid (None only for an empty grid)
+    #[must_use]
+    pub fn get(&self, row: isize, col: isize) -> Option<&T> {
+        self.grid.get(&self.pos_at(row, col)?)
+    }
+
+    /// Gets a mutable reference to the value at `row` and `col`, wrapped into the grid (None only for an empty grid)
+    #[must_use]
+    pub fn get_mut(&mut self, row: isize, col: isize) -> Option<&mut T> {
+        let pos = self.pos_at(row, col)?;
+        self.grid.get_mut(&pos)
+    }
+
+    /// Gets the position `d_row` rows and `d_col` columns away from `position`, wrapping around the edges
+    ///
+    /// # Panics
+    ///
+    /// When `position` is outside the grid
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let grid = Grid::new(3, vec![0; 6]).into_wrapping();
+    /// let pos = grid.pos_at(1, 2).unwrap();
+    /// assert_eq!(grid.offset(&pos, 0, 0), pos);
+    /// // Huge offsets do not overflow, they are reduced to the grid size first
+    /// assert_eq!(grid.offset(&pos, isize::MAX, isize::MAX), grid.offset(&pos, 1, 1));
+    /// assert_eq!(grid.offset(&pos, isize::MIN, isize::MIN), grid.offset(&pos, 0, 1));
+    /// ```
+    pub fn offset(&self, position: &GridPos, d_row: isize, d_col: isize) -> GridPos {
+        let (row, col) = self
+            .grid
+            .row_col(position)
+            .expect("GridPos outside of the grid");
+        let (width, height) = (self.grid.width(), self.grid.height());
+        let wrap = |index: usize, delta: isize, len: usize| {
+            (index as i128 + delta as i128).rem_euclid(len as i128) as usize
+        };
+        GridPos::new(wrap(row, d_row, height) * width + wrap(col, d_col, width))
+    }
+
+    /// Gets the position one step in `direction` from `position`, wrapping around the edges
+    ///
+    /// # Panics
+    ///
+    /// When `position` is outside the grid
+    pub fn neighbor(&self, position: &GridPos, direction: Direction) -> GridPos {
+        let (d_row, d_col) = direction.offset();
+        self.offset(position, d_row, d_col)
+    }
+
+    /// Gets the 4 neighbors Up, Right, Down, Left like `Grid::get_neighbors_wrapping`
+    ///
+    /// # Panics
+    ///
+    /// When `position` is outside the grid
+    pub fn get_neighbors(&self, position: &GridPos) -> [GridPos; 4] {
+        Direction::ORTHOGONAL.map(|direction| self.neighbor(position, direction))
+    }
+
+    /// Gets the 8 neighbors clockwise from Up, wrapping around the edges
+    ///
+    /// # Panics
+    ///
+    /// When `position` is outside the grid
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let grid = Grid::new(3, vec![0; 9]).into_wrapping();
+    /// let neighbors = grid.get_neighbors8(&grid.pos_at(2, 2).unwrap());
+    /// assert_eq!(neighbors, [5, 3, 6, 0, 2, 1, 7, 4].map(GridPos::new));
+    /// ```
+    pub fn get_neighbors8(&self, position: &GridPos) -> [GridPos; 8] {
+        Direction::ALL.map(|direction| self.neighbor(position, direction))
+    }
+
+    /// Gets the neighbors in the given `Neighborhood`, wrapping around the edges
+    ///
+    /// # Panics
+    ///
+    /// When `position` is outside the grid
+    #[must_use]
+    pub fn neighbors_in(&self, position: &GridPos, neighborhood: Neighborhood) -> Vec<GridPos> {
+        neighborhood
+            .directions()
+            .iter()
+            .map(|direction| self.neighbor(position, *direction))
+            .collect()
+    }
+
+    /// Gets the positions of the connected region of cells equal to the value at `start`,
+    /// across the edges
+    ///
+    /// Calls `flood_region_with(start, Neighborhood::Orthogonal)`
+    #[must_use]
+    pub fn flood_region(&self, start: &GridPos) -> Vec<GridPos>
+    where
+        T: PartialEq,
+    {
+        self.flood_region_with(start, Neighborhood::Orthogonal)
+    }
+
+    /// Gets the positions of the region of cells equal to the value at `start`,
+    /// connected through the given `Neighborhood` across the edges
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let grid = Grid::from(vec![
+    ///     vec![1, 0, 1],
+    ///     vec![0, 0, 0],
+    ///     vec![1, 0, 1]]);
+    /// let corner = grid.pos_at(0, 0).unwrap();
+    /// assert_eq!(grid.flood_region(&corner).len(), 1);
+    ///
+    /// let torus = grid.into_wrapping();
+    /// assert_eq!(torus.flood_region_with(&corner, Neighborhood::Orthogonal).len(), 4);
+    /// assert_eq!(torus.flood_region(&corner).len(), 4);
+    /// ```
+    #[must_use]
+    pub fn flood_region_with(&self, start: &GridPos, neighborhood: Neighborhood) -> Vec<GridPos>
+    where
+        T: PartialEq,
+    {
+        let Some(value) = self.grid.get(start) else {
+            return Vec::new();
+        };
+        self.grid.region_by(
+            start,
+            |pos| self.neighbors_in(pos, neighborhood),
+            |other| other == value,
+        )
+    }
+
+    /// Replaces the connected region of cells equal to the value at `start` with `new_value`,
+    /// across the edges. Returns the number of changed cells
+    ///
+    /// Calls `flood_fill_with(start, Neighborhood::Orthogonal, new_value)`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let mut torus = Grid::from(vec![
+    ///     vec!['.', '#', '.'],
+    ///     vec!['#', '#', '#'],
+    ///     vec!['.', '#', '.']]).into_wrapping();
+    /// assert_eq!(torus.flood_fill(&GridPos::new(0), 'o'), 4);
+    /// assert_eq!(torus.iter().collect::<String>(), "o#o###o#o");
+    /// ```
+    pub fn flood_fill(&mut self, start: &GridPos, new_value: T) -> usize
+    where
+        T: Clone + PartialEq,
+    {
+        self.flood_fill_with(start, Neighborhood::Orthogonal, new_value)
+    }
+
+    /// Replaces the region of cells equal to the value at `start`,
+    /// connected through the given `Neighborhood` across the edges, with `new_value`.
+    /// Returns the number of changed cells
+    pub fn flood_fill_with(
+        &mut self,
+        start: &GridPos,
+        neighborhood: Neighborhood,
+        new_value: T,
+    ) -> usize
+    where
+        T: Clone + PartialEq,
+    {
+        if self.grid.get(start).is_none_or(|value| *value == new_value) {
+            return 0;
+        }
+        let region = self.flood_region_with(start, neighborhood);
+        for pos in &region {
+            self.grid.put(pos, new_value.clone());
+        }
+        region.len()
+    }
+
+    /// Labels every cell with the index of its connected region across the edges,
+    /// like `Grid::connected_components`. Returns the label grid and the number of regions
+    ///
+    /// Calls `connected_components_with(Neighborhood::Orthogonal, same_region)`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use grid::*;
+    /// let grid = Grid::from(vec![
+    ///     vec![1, 0, 1],
+    ///     vec![0, 0, 0],
+    ///     vec![1, 0, 1]]);
+    /// assert_eq!(grid.connected_components(|a, b| a == b).1, 5);
+    ///
+    /// let torus = grid.into_wrapping();
+    /// let (labels, count) = torus.connected_components(|a, b| a == b);
+    /// assert_eq!(count, 2);
+    /// assert_eq!(labels.iter().copied().collect::<Vec<_>>(), vec![
+    ///     0, 1, 0,
+    ///     1, 1, 1,
+    ///     0, 1, 0]);
+    /// ```
+    pub fn connected_components(
+        &self,
+        same_region: impl Fn(&T, &T) -> bool,
+    ) -> (Grid<usize>, usize) {
+        self.connected_components_with(Neighborhood::Orthogonal, same_region)
+    }
+
+    /// Same as `connected_components`, with cells connected through the given `Neighborhood`
+    pub fn connected_components_with(
+        &self,
+        neighborhood: Neighborhood,
+        same_region: impl Fn(&T, &T) -> bool,
+    ) -> (Grid<usize>, usize) {
+        self.grid
+            .components_by(|pos| self.neighbors_in(pos, neighborhood), same_region)
+    }
+}
+
+impl<T> From<Grid<T>> for WrappingGrid<T> {
+    fn from(grid: Grid<T>) -> Self {
+        WrappingGrid::new(grid)
+    }
+}
+
+impl<T> Deref for WrappingGrid<T> {
+    type Target = Grid<T>;
+
+    fn deref(&self) -> &Grid<T> {
+        &self.grid
+    }
+}
+
+impl<T> DerefMut for WrappingGrid<T> {
+    fn deref_mut(&mut self) -> &mut Grid<T> {
+        &mut self.grid
+    }
+}
@@ -1,7 +1,47 @@
+mod grid_automaton;
+mod grid_bit;
+mod grid_builder;
+mod grid_chunked;
+mod grid_collect;
+mod grid_coord;
+mod grid_direction;
+mod grid_display;
+mod grid_draw;
+mod grid_error;
 mod grid_grid;
+mod grid_grid3;
+mod grid_history;
+#[cfg(feature = "image")]
+mod grid_image;
+mod grid_neighbors;
+mod grid_parse;
+mod grid_path;
 mod grid_pos;
+mod grid_resize;
+mod grid_search;
+#[cfg(feature = "serde")]
+mod grid_serde;
+mod grid_sparse;
+mod grid_transform;
+mod grid_traversal;
+mod grid_view;
+mod grid_wrapping;
 mod macros;
 
-pub use macros::*;
+pub use grid_automaton::CellNeighborhood;
+pub use grid_bit::BitGrid;
+pub use grid_builder::GridBuilder;
+pub use grid_chunked::ChunkedGrid;
+pub use grid_collect::IntoGrid;
+pub use grid_coord::Coord;
+pub use grid_direction::Direction;
+pub use grid_display::{CellStyle, Color, DisplayWith};
+pub use grid_error::{GridError, ParseGridError};
 pub use grid_grid::Grid;
+pub use grid_grid3::{Grid3, Grid3Pos};
+pub use grid_history::Change;
+pub use grid_neighbors::{Metric, Neighborhood, Neighbors};
 pub use grid_pos::GridPos;
+pub use grid_sparse::{SparseGrid, SparsePos};
+pub use grid_view::{GridView, GridViewMut, OrientedView};
+pub use grid_wrapping::WrappingGrid;
@@ -1,5 +1,13 @@
 /// Create a grid
 ///
+/// Either from rows, or like `vec![value; n]` as `grid![value; width; height]`
+/// (see [`Grid::filled`](crate::Grid::filled)).
+/// Without any rows the grid is empty, with width and height 0.
+///
+/// # Panics
+///
+/// When the rows do not all have the same length
+///
 /// # Example
 ///
 /// ```
@@ -15,18 +23,41 @@
 /// assert_eq!(grid.width(), 5);
 /// assert_eq!(grid.get(&grid.pos_at(2, 2).unwrap()), Some(&1));
 /// assert_eq!(grid.get(&grid.pos_at(3, 4).unwrap()), Some(&4));
+///
+/// let walls = grid!['#'; 4; 2];
+/// assert_eq!((walls.width(), walls.height()), (4, 2));
+/// assert!(walls.iter().all(|&c| c == '#'));
+///
+/// let empty: Grid<i32> = grid![];
+/// assert_eq!((empty.width(), empty.height(), empty.size()), (0, 0, 0));
+/// assert_eq!(empty.get_neighbors(&GridPos::new(0)), [None; 4]);
+/// ```
+///
+/// The macro does not need `Grid` to be imported
+///
+/// ```
+/// mod no_imports {
+///     pub fn make() -> grid::Grid<u8> {
+///         grid::grid![[1, 2], [3, 4]]
+///     }
+/// }
+/// assert_eq!(no_imports::make().height(), 2);
 /// ```
 #[macro_export]
 macro_rules! grid {
-    ( $( $x:expr ),* ) => {
+    ( $value:expr; $width:expr; $height:expr ) => {
+        $crate::Grid::filled($width, $height, $value)
+    };
+    () => {
+        $crate::Grid::new(0, ::std::vec::Vec::new())
+    };
+    ( $( $x:expr ),+ $(,)? ) => {
         {
-            let mut rows = Vec::new();
+            let mut rows = ::std::vec::Vec::new();
             $(
-                rows.push( Vec::from( $x ) );
-            )*
-            let width = rows.get(0).map(Vec::len).or(Some(0)).unwrap();
-            let data = rows.into_iter().flatten().collect();
-            Grid::new(width, data)
+                rows.push( ::std::vec::Vec::from( $x ) );
+            )+
+            $crate::Grid::from(rows)
         }
     };
 }